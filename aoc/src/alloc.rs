@@ -0,0 +1,189 @@
+//! A global allocator that counts allocations, for reporting memory use per phase
+//!
+//! Enable the `count-alloc` feature of `aoc` to install it, e.g.
+//! `cargo run --package day06 --features aoc/count-alloc`,
+//! or install it manually in a binary with
+//! ```ignore
+//! #[global_allocator]
+//! static GLOBAL: aoc::alloc::CountingAlloc = aoc::alloc::CountingAlloc;
+//! ```
+//!
+//! # Examples
+//! ```
+//! use aoc::alloc::{self, format_bytes};
+//!
+//! // Stats are only reported when the counting allocator is installed
+//! let (sum, stats) = alloc::measure(|| (1..=3).sum::<u32>());
+//! assert_eq!(sum, 6);
+//! assert_eq!(stats.is_some(), alloc::is_active());
+//!
+//! assert_eq!(format_bytes(512), "512 B");
+//! assert_eq!(format_bytes(1536), "1.50 KiB");
+//! ```
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering::Relaxed},
+};
+
+/// Wraps the system allocator, keeping allocation counts for each thread
+pub struct CountingAlloc;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Allocations made by a thread so far
+#[derive(Clone, Copy)]
+struct Counts {
+    allocations: usize,
+    bytes: usize,
+    /// Bytes allocated minus bytes freed by this thread, which can go negative
+    /// when it frees memory allocated by another thread
+    live: isize,
+    peak: isize,
+}
+
+thread_local! {
+    // Const initialised without a destructor, so that using it never allocates
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn update_counts(f: impl FnOnce(&mut Counts)) {
+    // Not available while the thread is being torn down
+    let _ = COUNTS.try_with(|cell| {
+        let mut counts = cell.get();
+        f(&mut counts);
+        cell.set(counts);
+    });
+}
+
+fn record_alloc(size: usize) {
+    ACTIVE.store(true, Relaxed);
+    update_counts(|counts| {
+        counts.allocations = counts.allocations.wrapping_add(1);
+        counts.bytes = counts.bytes.wrapping_add(size);
+        counts.live = counts.live.wrapping_add(size as isize);
+        counts.peak = counts.peak.max(counts.live);
+    });
+}
+
+fn record_dealloc(size: usize) {
+    update_counts(|counts| counts.live = counts.live.wrapping_sub(size as isize));
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    /// Counted as allocating the new size and freeing the old
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Whether the counting allocator is installed (and has been used)
+pub fn is_active() -> bool {
+    ACTIVE.load(Relaxed)
+}
+
+/// Allocations made by a thread while running some code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct AllocStats {
+    /// Number of allocations (including reallocations)
+    pub allocations: usize,
+    /// Total bytes allocated
+    pub bytes: usize,
+    /// Most bytes live at once, above what was live beforehand
+    pub peak: usize,
+}
+
+/// Count the allocations made by `f`, returning `None` for the stats if the
+/// counting allocator isn't installed.
+/// Only allocations made by the current thread are counted, so threads running
+/// at the same time (such as the workers of timed out parts) don't skew the stats.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    if !is_active() {
+        return (f(), None);
+    }
+
+    let before = COUNTS.with(Cell::get);
+    COUNTS.with(|cell| {
+        cell.set(Counts {
+            peak: before.live,
+            ..before
+        })
+    });
+
+    let result = f();
+
+    let after = COUNTS.with(Cell::get);
+    let stats = AllocStats {
+        allocations: after.allocations.wrapping_sub(before.allocations),
+        bytes: after.bytes.wrapping_sub(before.bytes),
+        peak: after.peak.saturating_sub(before.live).max(0) as usize,
+    };
+    (result, Some(stats))
+}
+
+/// Format a byte count with a binary unit, e.g. `1.50 KiB`
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.2} {}", UNITS[unit])
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
@@ -0,0 +1,118 @@
+//! The answer to one part of a puzzle
+//!
+//! # Examples
+//! ```
+//! use aoc::Answer;
+//!
+//! assert_eq!(Answer::from(42_usize), Answer::from(42_u8));
+//! assert_eq!(Answer::from("ABC").to_string(), "ABC");
+//! assert!(Answer::from(-7_i64).matches("-7"));
+//! assert_eq!(Answer::from(()), Answer::Unsolved);
+//! ```
+
+use std::fmt;
+
+use crate::utils::grid::Grid;
+
+/// A puzzle answer with a canonical display form
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Any integer, regardless of the width it was computed with
+    Int(i128),
+    Text(String),
+    /// A grid rendered one row per line, e.g. for answers read off a picture
+    Grid(String),
+    /// The part hasn't been solved yet
+    Unsolved,
+}
+
+impl Answer {
+    /// Whether this answer's canonical form matches a recorded answer
+    pub fn matches(&self, recorded: &str) -> bool {
+        self.to_string() == recorded
+    }
+
+    pub fn is_unsolved(&self) -> bool {
+        matches!(self, Answer::Unsolved)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{x}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Grid(grid) => write!(f, "{grid}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int: ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Answer::Int(value.into())
+            }
+        })*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+/// Values too large for an `i128` are kept as text
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value)
+            .map(Answer::Int)
+            .unwrap_or_else(|_| Answer::Text(value.to_string()))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> From<Grid<T>> for Answer {
+    fn from(value: Grid<T>) -> Self {
+        Answer::Grid(value.to_string().trim_end().to_owned())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+impl From<()> for Answer {
+    fn from(_: ()) -> Self {
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,86 @@
+//! Criterion benchmarks for any [`Puzzle`], enabled with the `bench` feature
+//!
+//! A day's benchmarks only need a `[[bench]]` target (with `harness = false`) containing
+//! ```ignore
+//! aoc::bench_main!(day11::Day11);
+//! ```
+//!
+//! Parameters can be overridden like `--param` does for the runners, with a whitespace
+//! separated list in the `AOC_PARAMS` environment variable, e.g.
+//! `AOC_PARAMS="day11.part2_blinks=50" cargo bench --package day11`
+
+use std::{env, fs, path::Path};
+
+pub use criterion;
+use criterion::{BatchSize, Criterion};
+
+use crate::{params, runner::solve, Puzzle};
+
+/// The environment variable holding `name=value` parameter overrides for benchmarks
+pub const PARAMS_VAR: &str = "AOC_PARAMS";
+
+/// Benchmark parsing and each part of a puzzle separately against an input
+pub fn bench_puzzle<T: Puzzle>(c: &mut Criterion, input: &str) {
+    let overrides: Vec<String> = env::var(PARAMS_VAR)
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(str::to_owned)
+        .collect();
+    let params: T::Params = match params::from_overrides(T::DAY, &overrides) {
+        Ok(params) => params,
+        Err(message) => {
+            eprintln!("[Skipping day {:02}] {message}", T::DAY);
+            return;
+        }
+    };
+    let parsed = match T::parse(input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("[Skipping day {:02}] {}", T::DAY, err.locate(input));
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{:02}", T::DAY));
+    group.bench_function("parse", |b| b.iter(|| T::parse(input)));
+    for part in [1, 2] {
+        group.bench_function(format!("part_{part}"), |b| {
+            b.iter_batched(
+                || T::clone_parsed(&parsed),
+                |owned| solve::<T>(part, &parsed, &params, owned),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// Find the input for a day in the [search paths](crate::input::search_paths),
+/// relative to the workspace root
+pub fn load_input(workspace_root: impl AsRef<Path>, day: u8) -> Option<String> {
+    let root = workspace_root.as_ref();
+    let input = crate::input::search_paths(day, None)
+        .iter()
+        .find_map(|path| fs::read_to_string(root.join(path)).ok());
+    if input.is_none() {
+        eprintln!("[Skipping day {day:02}] no input found");
+    }
+    input
+}
+
+/// Generate a criterion `main` benchmarking a puzzle against its input
+#[macro_export]
+macro_rules! bench_main {
+    ($puzzle: ty) => {
+        fn criterion_benchmark(c: &mut $crate::bench::criterion::Criterion) {
+            let root = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+            let day = <$puzzle as $crate::Puzzle>::DAY;
+            if let Some(input) = $crate::bench::load_input(root, day) {
+                $crate::bench::bench_puzzle::<$puzzle>(c, &input);
+            }
+        }
+
+        $crate::bench::criterion::criterion_group!(benches, criterion_benchmark);
+        $crate::bench::criterion::criterion_main!(benches);
+    };
+}
@@ -0,0 +1,298 @@
+//! Differential testing of alternative solvers against a puzzle's own
+//!
+//! Alternative implementations of a part are run on the same inputs as the puzzle's
+//! `solve_part1`/`solve_part2`, including randomly generated ones, and any disagreement
+//! is reported with the smallest input (by removing lines then characters) that still disagrees.
+//! Both are given the same parameters, the puzzle's defaults unless overridden with
+//! [`Differential::params`].
+//!
+//! # Examples
+//! ```
+//! use aoc::differential::{shrink, Rng};
+//!
+//! let mut rng = Rng::new(7);
+//! let roll = rng.range(1..7);
+//! assert!((1..7).contains(&roll));
+//! assert_eq!(Rng::new(7).range(1..7), roll);
+//!
+//! // The smallest input that still contains a 3
+//! let minimal = shrink("12\n34\n56\n", |input| input.contains('3'));
+//! assert_eq!(minimal, "3\n");
+//!
+//! // Shrunk inputs end every line with `\n`, so failures that depend on the
+//! // original line endings are given back as they were
+//! let minimal = shrink("12\r\n34", |input| input.contains('\r'));
+//! assert_eq!(minimal, "12\r\n34");
+//! ```
+
+use std::{fmt, ops::Range};
+
+use crate::{
+    panics::{self, Panic},
+    Answer, Puzzle,
+};
+
+/// A small seedable random number generator (xorshift64*) for generating inputs
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// A number in `range`, panics if it's empty
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "can't pick from an empty range");
+        range.start + (self.next_u64() % (range.end - range.start) as u64) as usize
+    }
+
+    /// True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= p
+    }
+
+    /// A random item of `items`, panics if it's empty
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+}
+
+type Solver<T> = Box<dyn Fn(&<T as Puzzle>::Parsed, &<T as Puzzle>::Params) -> Answer>;
+
+/// Generates a random puzzle input
+pub type Generator = fn(&mut Rng) -> String;
+
+/// An alternative implementation of a part
+struct Alternative<T: Puzzle> {
+    name: String,
+    part: u8,
+    solve: Solver<T>,
+}
+
+/// Runs alternative implementations of a puzzle's parts against its own
+pub struct Differential<T: Puzzle> {
+    alternatives: Vec<Alternative<T>>,
+    inputs: Vec<(String, String)>,
+    random: Option<(usize, Generator)>,
+    seed: u64,
+    params: T::Params,
+}
+
+/// An input where an alternative gave a different answer to the puzzle's own solver
+#[derive(Debug, Clone)]
+pub struct Disagreement {
+    pub alternative: String,
+    pub part: u8,
+    /// Where the input came from
+    pub source: String,
+    /// The smallest input found that still disagrees
+    pub input: String,
+    pub expected: Result<Answer, Panic>,
+    pub actual: Result<Answer, Panic>,
+}
+
+impl<T: Puzzle> Default for Differential<T> {
+    fn default() -> Self {
+        Self {
+            alternatives: Vec::new(),
+            inputs: Vec::new(),
+            random: None,
+            seed: 2024,
+            params: T::Params::default(),
+        }
+    }
+}
+
+impl<T: Puzzle> Differential<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an alternative implementation of part 1
+    pub fn part1<A: Into<Answer>>(
+        self,
+        name: &str,
+        solve: impl Fn(&T::Parsed, &T::Params) -> A + 'static,
+    ) -> Self {
+        self.alternative(1, name, solve)
+    }
+
+    /// Register an alternative implementation of part 2
+    pub fn part2<A: Into<Answer>>(
+        self,
+        name: &str,
+        solve: impl Fn(&T::Parsed, &T::Params) -> A + 'static,
+    ) -> Self {
+        self.alternative(2, name, solve)
+    }
+
+    fn alternative<A: Into<Answer>>(
+        mut self,
+        part: u8,
+        name: &str,
+        solve: impl Fn(&T::Parsed, &T::Params) -> A + 'static,
+    ) -> Self {
+        self.alternatives.push(Alternative {
+            name: name.to_owned(),
+            part,
+            solve: Box::new(move |input, params| solve(input, params).into()),
+        });
+        self
+    }
+
+    /// Compare on a fixed input, such as an example
+    pub fn input(mut self, name: &str, input: &str) -> Self {
+        self.inputs.push((name.to_owned(), input.to_owned()));
+        self
+    }
+
+    /// Compare on `cases` inputs from `generate`
+    pub fn random(mut self, cases: usize, generate: Generator) -> Self {
+        self.random = Some((cases, generate));
+        self
+    }
+
+    /// Seed the random inputs, so that a failure can be reproduced
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Override the parameters given to the solvers, e.g. to match smaller generated inputs
+    pub fn params(mut self, params: T::Params) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Compare every alternative on every input, returning the first disagreement
+    pub fn run(&self) -> Result<(), Box<Disagreement>> {
+        let mut inputs = self.inputs.clone();
+        if let Some((cases, generate)) = self.random {
+            let mut rng = Rng::new(self.seed);
+            inputs.extend((0..cases).map(|case| {
+                let source = format!("random input {case} (seed {})", self.seed);
+                (source, generate(&mut rng))
+            }));
+        }
+
+        for (source, input) in inputs {
+            for alternative in &self.alternatives {
+                if let Some(original) = self.compare(alternative, &input) {
+                    let shrunk = shrink(&input, |input| self.compare(alternative, input).is_some());
+                    // A flaky disagreement might not reproduce, so keep the original to report
+                    let (input, (expected, actual)) = match self.compare(alternative, &shrunk) {
+                        Some(result) => (shrunk, result),
+                        None => (input, original),
+                    };
+                    return Err(Box::new(Disagreement {
+                        alternative: alternative.name.clone(),
+                        part: alternative.part,
+                        source,
+                        input,
+                        expected,
+                        actual,
+                    }));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Panic with a report of the first disagreement, if any
+    #[track_caller]
+    pub fn assert_agree(&self) {
+        if let Err(disagreement) = self.run() {
+            panic!("{disagreement}");
+        }
+    }
+
+    /// The expected and actual answers if an alternative disagrees on an input.
+    /// Inputs that don't parse are skipped.
+    fn compare(
+        &self,
+        alternative: &Alternative<T>,
+        input: &str,
+    ) -> Option<(Result<Answer, Panic>, Result<Answer, Panic>)> {
+        let parsed = panics::catch(|| T::parse(input)).ok()?.ok()?;
+        let expected = panics::catch(|| match alternative.part {
+            1 => T::solve_part1(&parsed, &self.params).into(),
+            _ => T::solve_part2(&parsed, &self.params).into(),
+        });
+        let actual = panics::catch(|| (alternative.solve)(&parsed, &self.params));
+        (expected != actual).then_some((expected, actual))
+    }
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |result: &Result<Answer, Panic>| match result {
+            Ok(answer) => answer.to_string(),
+            Err(panic) => panic.to_string(),
+        };
+        writeln!(
+            f,
+            "{} disagrees on part {} of {}",
+            self.alternative, self.part, self.source
+        )?;
+        writeln!(f, "  expected: {}", describe(&self.expected))?;
+        writeln!(f, "  actual:   {}", describe(&self.actual))?;
+        write!(f, "minimal input:\n{}", self.input)
+    }
+}
+
+/// Shrink an input that `fails` to the smallest one that still does,
+/// by removing chunks of lines and then chunks of characters from each line.
+/// Lines are rejoined with `\n`, so if that alone stops it failing (e.g. it depends on
+/// CRLF line endings or a missing trailing newline) the input is returned unchanged.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join =
+        |lines: &[String]| -> String { lines.iter().map(|line| format!("{line}\n")).collect() };
+
+    let mut lines: Vec<String> = input.lines().map(str::to_owned).collect();
+    if !fails(&join(&lines)) {
+        return input.to_owned();
+    }
+    lines = shrink_items(lines, |lines| fails(&join(lines)));
+
+    for i in 0..lines.len() {
+        let chars: Vec<char> = lines[i].chars().collect();
+        let chars = shrink_items(chars, |chars| {
+            let mut candidate = lines.clone();
+            candidate[i] = chars.iter().collect();
+            fails(&join(&candidate))
+        });
+        lines[i] = chars.into_iter().collect();
+    }
+    join(&lines)
+}
+
+/// Remove chunks of items while `fails` still holds, halving the chunk size down to one
+fn shrink_items<T: Clone>(mut items: Vec<T>, fails: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut chunk = items.len().div_ceil(2).max(1);
+    loop {
+        let mut start = 0;
+        while start < items.len() {
+            let end = (start + chunk).min(items.len());
+            let candidate = [&items[..start], &items[end..]].concat();
+            if fails(&candidate) {
+                items = candidate;
+            } else {
+                start = end;
+            }
+        }
+
+        if chunk == 1 {
+            return items;
+        }
+        chunk = chunk.div_ceil(2);
+    }
+}
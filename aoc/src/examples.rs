@@ -0,0 +1,233 @@
+//! Support for testing puzzles against the worked examples from the puzzle text
+//!
+//! See [`examples!`](crate::examples!) for generating tests.
+
+use crate::{Answer, Puzzle};
+
+/// An example puzzle input, either inline in the source or read from a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+}
+
+impl Example {
+    /// An example written inline, which will be dedented
+    pub fn inline(text: &str) -> Self {
+        let input = dedent(text);
+        let first_line = input.lines().next().unwrap_or_default();
+        Self {
+            name: format!("inline example {first_line:?}"),
+            input,
+        }
+    }
+
+    /// An example read from a file, used as is
+    pub fn file(path: &str, contents: &str) -> Self {
+        Self {
+            name: path.to_owned(),
+            input: contents.to_owned(),
+        }
+    }
+
+    /// Mention the parameters overridden for this example in its name
+    pub fn with_params(mut self, params: &str) -> Self {
+        self.name = format!("{} with {{ {params} }}", self.name);
+        self
+    }
+}
+
+/// Remove the common leading indentation from an indented string literal.
+///
+/// Leading and trailing blank lines are dropped, and the first line is ignored
+/// when finding the common indentation if it starts right after the opening quote.
+///
+/// # Examples
+/// ```
+/// use aoc::examples::dedent;
+///
+/// let text = "
+///     1 2
+///       3 4
+///     ";
+/// assert_eq!(dedent(text), "1 2\n  3 4\n");
+/// assert_eq!(dedent("1 2
+///     3 4"), "1 2\n3 4\n");
+/// ```
+pub fn dedent(text: &str) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let first_is_inline = lines.first().is_some_and(|line| !line.trim().is_empty());
+    while lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let indent = lines
+        .iter()
+        .skip(usize::from(first_is_inline))
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 && first_is_inline {
+                line.trim_start()
+            } else {
+                line.get(indent..).unwrap_or_else(|| line.trim_start())
+            }
+        })
+        .fold(String::new(), |mut out, line| {
+            out.push_str(line);
+            out.push('\n');
+            out
+        })
+}
+
+fn parse_example<T: Puzzle>(example: &Example) -> T::Parsed {
+    T::parse(&example.input)
+        .unwrap_or_else(|err| panic!("\n{}", err.locate(&example.input).report(&example.name)))
+}
+
+/// Assert that a part solved from its own copy of the input agrees, if the puzzle opts in to that
+fn check_owned<T: Puzzle>(
+    part: u8,
+    parsed: &T::Parsed,
+    params: &T::Params,
+    expected: &Answer,
+    example: &Example,
+) {
+    if let Some(owned) = T::clone_parsed(parsed) {
+        let answer = crate::runner::solve::<T>(part, parsed, params, Some(owned));
+        assert_eq!(
+            &answer, expected,
+            "Part {part} of {} (from an owned input)",
+            example.name
+        );
+    }
+}
+
+/// Assert that part 1 of a puzzle gives the expected answer for an example
+pub fn check_part1<T: Puzzle>(example: &Example, params: &T::Params, expected: Option<Answer>) {
+    if let Some(expected) = expected {
+        let parsed = parse_example::<T>(example);
+        let answer = T::solve_part1(&parsed, params).into();
+        assert_eq!(answer, expected, "Part 1 of {}", example.name);
+        check_owned::<T>(1, &parsed, params, &expected, example);
+    }
+}
+
+/// Assert that part 2 of a puzzle gives the expected answer for an example
+pub fn check_part2<T: Puzzle>(example: &Example, params: &T::Params, expected: Option<Answer>) {
+    if let Some(expected) = expected {
+        let parsed = parse_example::<T>(example);
+        let answer = T::solve_part2(&parsed, params).into();
+        assert_eq!(answer, expected, "Part 2 of {}", example.name);
+        check_owned::<T>(2, &parsed, params, &expected, example);
+
+        let owned = T::clone_parsed(&parsed);
+        let (_, carry) = crate::runner::solve_carry::<T>(&parsed, params, owned);
+        let answer = T::solve_part2_carry(&parsed, params, carry).into();
+        assert_eq!(
+            answer, expected,
+            "Part 2 of {} (carried from part 1)",
+            example.name
+        );
+    }
+}
+
+/// Generate `#[test]`s checking a puzzle's answers for each of its examples.
+///
+/// Examples are either a path to a file relative to the crate root, or an inline
+/// string prefixed with `inline` which is dedented before use. Use `_` to skip
+/// checking a part, and `with { name: value }` to override the puzzle's parameters.
+///
+/// ```ignore
+/// aoc::examples!(Day05,
+///     "sample.txt" => (143, 123),
+///     inline "
+///         1|2
+///
+///         1,2
+///     " => (2, _),
+/// );
+/// aoc::examples!(Day11,
+///     inline "125 17" with { part1_blinks: 6 } => (22, _),
+/// );
+/// ```
+#[macro_export]
+macro_rules! examples {
+    (@munch $puzzle: ty, $done: tt, inline $text: literal $($rest: tt)*) => {
+        $crate::examples!(@params $puzzle, $done, ($crate::examples::Example::inline($text)) $($rest)*);
+    };
+    (@munch $puzzle: ty, $done: tt, $file: literal $($rest: tt)*) => {
+        $crate::examples!(@params $puzzle, $done, (
+            $crate::examples::Example::file(
+                $file,
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $file)),
+            )
+        ) $($rest)*);
+    };
+    (@params $puzzle: ty, [$($done: tt)*], ($example: expr)
+        with { $($name: ident: $value: expr),* $(,)? } => ($p1: tt, $p2: tt) $(, $($rest: tt)*)?
+    ) => {
+        $crate::examples!(@munch $puzzle, [
+            $($done)*
+            (
+                $example.with_params(stringify!($($name: $value),*)),
+                {
+                    let mut params = <$puzzle as $crate::Puzzle>::Params::default();
+                    $(params.$name = $value;)*
+                    params
+                },
+                $p1,
+                $p2
+            )
+        ], $($($rest)*)?);
+    };
+    (@params $puzzle: ty, [$($done: tt)*], ($example: expr) => ($p1: tt, $p2: tt) $(, $($rest: tt)*)?) => {
+        $crate::examples!(@munch $puzzle, [
+            $($done)*
+            ($example, Default::default(), $p1, $p2)
+        ], $($($rest)*)?);
+    };
+    (@munch $puzzle: ty, [$(($example: expr, $params: expr, $p1: tt, $p2: tt))*], ) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn part1() {
+                $($crate::examples::check_part1::<$puzzle>(
+                    &$example,
+                    &$params,
+                    $crate::examples!(@expected $p1),
+                );)*
+            }
+
+            #[test]
+            fn part2() {
+                $($crate::examples::check_part2::<$puzzle>(
+                    &$example,
+                    &$params,
+                    $crate::examples!(@expected $p2),
+                );)*
+            }
+        }
+    };
+    (@expected _) => {
+        None
+    };
+    (@expected $expected: tt) => {
+        Some($crate::Answer::from($expected))
+    };
+    ($puzzle: ty, $($examples: tt)+) => {
+        $crate::examples!(@munch $puzzle, [], $($examples)+);
+    };
+}
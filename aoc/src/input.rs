@@ -0,0 +1,108 @@
+//! Finding and reading a day's puzzle input
+//!
+//! The input is read from the first of
+//! 1. an explicit path, or `-` for stdin
+//! 2. `dayNN.txt` in the directory given by `--input-dir`
+//! 3. `dayNN.txt` in the directory given by the `AOC_INPUT_DIR` environment variable
+//! 4. `input/dayNN.txt`
+//! 5. `input.txt` in the day crate's own directory, wherever it's run from
+//!
+//! # Examples
+//! ```
+//! use std::path::{Path, PathBuf};
+//! use aoc::input;
+//!
+//! let paths = input::search_paths(6, Some(Path::new("puzzles")));
+//! assert_eq!(paths[0], PathBuf::from("puzzles/day06.txt"));
+//! assert!(paths.contains(&PathBuf::from("input/day06.txt")));
+//! assert!(paths.last().unwrap().ends_with("day06/input.txt"));
+//! assert!(paths.last().unwrap().is_absolute());
+//! ```
+
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::RunError;
+
+/// The environment variable naming a directory of `dayNN.txt` inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// A puzzle input and where it was read from
+#[derive(Debug, Clone)]
+pub struct Input {
+    /// The path read, or `<stdin>`
+    pub source: String,
+    pub text: String,
+}
+
+impl Input {
+    /// Read the input for a day from `path` if given, otherwise from the first of the
+    /// [search paths](search_paths) that exists
+    pub fn read(day: u8, path: Option<&str>, input_dir: Option<&Path>) -> Result<Self, RunError> {
+        match path {
+            Some("-") => read_stdin(),
+            Some(path) => read_file(Path::new(path)),
+            None => {
+                let tried = search_paths(day, input_dir);
+                match tried.iter().find(|path| path.is_file()) {
+                    Some(path) => read_file(path),
+                    None => Err(RunError::NoInput { day, tried }),
+                }
+            }
+        }
+    }
+}
+
+/// Where to look for a day's input when no path is given, in order
+pub fn search_paths(day: u8, input_dir: Option<&Path>) -> Vec<PathBuf> {
+    let file_name = format!("day{day:02}.txt");
+    let env_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    let candidates = [
+        input_dir.map(|dir| dir.join(&file_name)),
+        env_dir.map(|dir| dir.join(&file_name)),
+        Some(Path::new("input").join(&file_name)),
+        Some(day_crate_dir(day).join("input.txt")),
+    ];
+
+    let mut paths = Vec::new();
+    for path in candidates.into_iter().flatten() {
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// The directory of a day's crate, which sits next to this one in the workspace
+fn day_crate_dir(day: u8) -> PathBuf {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate is inside a workspace");
+    workspace.join(format!("day{day:02}"))
+}
+
+fn read_file(path: &Path) -> Result<Input, RunError> {
+    let source = path.display().to_string();
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Input { source, text }),
+        Err(err) => Err(RunError::Read {
+            path: source,
+            source: err,
+        }),
+    }
+}
+
+fn read_stdin() -> Result<Input, RunError> {
+    let source = "<stdin>".to_owned();
+    let mut text = String::new();
+    match io::stdin().read_to_string(&mut text) {
+        Ok(_) => Ok(Input { source, text }),
+        Err(err) => Err(RunError::Read {
+            path: source,
+            source: err,
+        }),
+    }
+}
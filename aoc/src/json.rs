@@ -0,0 +1,119 @@
+//! JSON lines records of a run, for `--format json`
+
+use serde::Serialize;
+
+use crate::{
+    alloc::AllocStats,
+    ledger::Verdict,
+    runner::{PartFailure, PartReport, PuzzleReport, RunError},
+    timing::Timing,
+    Answer,
+};
+
+/// The outcome of one part, or of a day that couldn't be run (with no part)
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: Option<u8>,
+    /// Missing if no input could be found
+    input: Option<&'a str>,
+    /// One of `ok`, `unsolved`, `pass`, `fail`, `unknown`, `panicked`, `timeout` or `error`
+    status: &'static str,
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    parse_timing: Option<TimingRecord>,
+    timing: Option<TimingRecord>,
+}
+
+/// Durations in nanoseconds
+#[derive(Debug, Serialize)]
+struct TimingRecord {
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    max_ns: u64,
+    stddev_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocs: Option<AllocStats>,
+}
+
+impl From<&Timing> for TimingRecord {
+    fn from(timing: &Timing) -> Self {
+        Self {
+            runs: timing.runs(),
+            min_ns: timing.min().as_nanos() as u64,
+            median_ns: timing.median().as_nanos() as u64,
+            mean_ns: timing.mean().as_nanos() as u64,
+            max_ns: timing.max().as_nanos() as u64,
+            stddev_ns: timing.stddev().as_nanos() as u64,
+            allocs: timing.allocs().copied(),
+        }
+    }
+}
+
+fn part_record<'a>(report: &'a PuzzleReport, part: &PartReport) -> Record<'a> {
+    let (status, expected, error) = match (&part.answer, &part.verdict) {
+        (Err(PartFailure::Panicked(panic)), _) => ("panicked", None, Some(panic.to_string())),
+        (Err(PartFailure::TimedOut(budget)), _) => (
+            "timeout",
+            None,
+            Some(format!("still running after {budget:.2?}")),
+        ),
+        (Ok(Answer::Unsolved), _) => ("unsolved", None, None),
+        (Ok(_), None) => ("ok", None, None),
+        (Ok(_), Some(Verdict::Pass)) => ("pass", None, None),
+        (Ok(_), Some(Verdict::Fail { expected })) => ("fail", Some(expected.clone()), None),
+        (Ok(_), Some(Verdict::Unknown)) => ("unknown", None, None),
+    };
+
+    Record {
+        day: report.day,
+        part: Some(part.part),
+        input: Some(&report.input_path),
+        status,
+        answer: part.answer.as_ref().ok().map(ToString::to_string),
+        expected,
+        error,
+        parse_timing: Some((&report.parse_timing).into()),
+        timing: part.timing.as_ref().map(Into::into),
+    }
+}
+
+fn print(record: &Record) {
+    println!(
+        "{}",
+        serde_json::to_string(record).expect("records always serialize")
+    );
+}
+
+/// Print a record for each part of a report
+pub(crate) fn print_report(report: &PuzzleReport) {
+    for part in &report.parts {
+        print(&part_record(report, part));
+    }
+}
+
+/// Print a record for a day that couldn't be run
+pub(crate) fn print_error(day: u8, err: &RunError) {
+    let input = match err {
+        RunError::Read { path, .. }
+        | RunError::Parse { path, .. }
+        | RunError::ParsePanic { path, .. } => Some(path.as_str()),
+        RunError::NoInput { .. } | RunError::Ledger { .. } | RunError::Params { .. } => None,
+    };
+    print(&Record {
+        day,
+        part: None,
+        input,
+        status: "error",
+        answer: None,
+        expected: None,
+        error: Some(err.to_string()),
+        parse_timing: None,
+        timing: None,
+    });
+}
@@ -0,0 +1,131 @@
+//! A local ledger of known-good answers, used to catch regressions
+//!
+//! The ledger is a TOML file with a table per day, e.g.
+//! ```toml
+//! [day06]
+//! part1 = "41"
+//! part2 = "6"
+//! ```
+//!
+//! # Examples
+//! ```
+//! use aoc::{ledger::{Ledger, Verdict}, Answer};
+//!
+//! let mut ledger = Ledger::default();
+//! ledger.record(6, 1, "41");
+//! assert_eq!(ledger.check(6, 1, &Answer::from(41)), Verdict::Pass);
+//! assert_eq!(ledger.check(6, 1, &Answer::from(42)), Verdict::Fail { expected: "41".to_owned() });
+//! assert_eq!(ledger.check(6, 2, &Answer::from(6)), Verdict::Unknown);
+//!
+//! // Unsolved parts never pass, even against a stale "unsolved" entry
+//! ledger.record(6, 2, "unsolved");
+//! assert_eq!(ledger.check(6, 2, &Answer::Unsolved), Verdict::Unknown);
+//! ```
+
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Answer;
+
+/// Recorded answers, keyed by day and part
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<String>,
+}
+
+/// The result of checking an answer against the ledger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// An error reading or writing a ledger file
+#[derive(Debug)]
+pub enum LedgerError {
+    Io(io::Error),
+    Format(String),
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerError::Io(err) => write!(f, "{err}"),
+            LedgerError::Format(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+impl Ledger {
+    /// Load a ledger from a file, treating a missing file as an empty ledger
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LedgerError> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| LedgerError::Format(e.to_string())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(LedgerError::Io(err)),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), LedgerError> {
+        let text = toml::to_string(self).map_err(|e| LedgerError::Format(e.to_string()))?;
+        fs::write(path, text).map_err(LedgerError::Io)
+    }
+
+    /// Get the recorded answer for a day and part
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.0.get(&Self::key(day))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Record an answer for a day and part, replacing any previous answer
+    /// panics if the part isn't 1 or 2
+    pub fn record(&mut self, day: u8, part: u8, answer: impl Into<String>) {
+        let answers = self.0.entry(Self::key(day)).or_default();
+        let slot = match part {
+            1 => &mut answers.part1,
+            2 => &mut answers.part2,
+            _ => panic!("Puzzles only have parts 1 and 2, not {part}"),
+        };
+        *slot = Some(answer.into());
+    }
+
+    /// Compare an answer against the recorded one, an unsolved part is always unknown
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            _ if answer.is_unsolved() => Verdict::Unknown,
+            None => Verdict::Unknown,
+            Some(expected) if answer.matches(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+
+    fn key(day: u8) -> String {
+        format!("day{day:02}")
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
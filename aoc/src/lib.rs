@@ -1,38 +1,105 @@
+pub mod alloc;
+pub mod answer;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod differential;
+pub mod examples;
+pub mod input;
+mod json;
+pub mod ledger;
+pub mod logging;
+pub mod panics;
+pub mod params;
+pub mod parse;
+pub mod progress;
+pub mod registry;
+pub mod runner;
+pub mod timing;
 pub mod utils;
 
-use std::{env, fmt, fs};
+pub use answer::Answer;
+pub use log;
+pub use parse::{ParseError, ParseResult};
+pub use registry::{DaySelection, PuzzleEntry, Registry};
+pub use runner::{
+    run_puzzle, run_puzzle_with_options, run_puzzle_with_path, try_run_puzzle, OutputFormat,
+    PartFailure, PartReport, PuzzleReport, RunError, RunOptions,
+};
 
-pub trait Puzzle {
-    type Parsed: Clone;
+pub trait Puzzle: 'static {
+    /// The day of the advent calendar this puzzle is for
+    const DAY: u8;
 
-    fn parse(input: &str) -> Self::Parsed;
+    /// The parsed input, shared between the worker threads that the parts run on
+    type Parsed: Send + Sync + 'static;
 
-    fn solve_part1(input: Self::Parsed) -> impl fmt::Debug;
+    /// State that part 1 can hand on to part 2, use `()` if the parts are independent.
+    /// Puzzles with `()` have their parts solved independently by the runner.
+    /// It's cloned for each repeated run of part 2 rather than re-running part 1,
+    /// and sent to part 2's worker thread.
+    type Carry: Default + Clone + Send + 'static;
 
-    fn solve_part2(input: Self::Parsed) -> impl fmt::Debug;
-}
+    /// Constants that differ between examples and the real input, use `()` if there are none.
+    /// They're passed to every solver, with any overrides from the command line or an example.
+    /// See [`params!`] for declaring them.
+    type Params: params::Params;
 
-pub fn run_puzzle<T: Puzzle>() {
-    // Read input
-    let input_path = env::args().nth(1).unwrap_or("./input.txt".to_owned());
-    run_puzzle_with_path::<T>(&input_path)
-}
+    fn parse(input: &str) -> ParseResult<Self::Parsed>;
+
+    /// Solvers borrow the parsed input so that it can be shared between parts,
+    /// those that need to mutate it can opt in to owning a copy (see [`Puzzle::clone_parsed`]).
+    fn solve_part1(input: &Self::Parsed, params: &Self::Params) -> impl Into<Answer>;
+
+    fn solve_part2(input: &Self::Parsed, params: &Self::Params) -> impl Into<Answer>;
+
+    /// Solvers that mutate their input can opt in to taking it by value, by returning
+    /// a copy here and overriding [`Puzzle::solve_part1_owned`]/[`Puzzle::solve_part2_owned`]
+    /// (or [`Puzzle::solve_part1_carry_owned`]). The runner makes the copy outside of the
+    /// timed region.
+    fn clone_parsed(input: &Self::Parsed) -> Option<Self::Parsed> {
+        let _ = input;
+        None
+    }
+
+    /// Solve part 1 from its own copy of the parsed input, see [`Puzzle::clone_parsed`]
+    fn solve_part1_owned(input: Self::Parsed, params: &Self::Params) -> impl Into<Answer> {
+        let answer: Answer = Self::solve_part1(&input, params).into();
+        answer
+    }
 
-pub fn run_puzzle_with_path<T: Puzzle>(input_path: &str) {
-    // Read input
-    let input_text = fs::read_to_string(input_path)
-        .unwrap_or_else(|_| panic!("Can't find AOC input file {}", &input_path));
-    println!("[Read {}]", input_path);
+    /// Solve part 2 from its own copy of the parsed input, see [`Puzzle::clone_parsed`]
+    fn solve_part2_owned(input: Self::Parsed, params: &Self::Params) -> impl Into<Answer> {
+        let answer: Answer = Self::solve_part2(&input, params).into();
+        answer
+    }
 
-    // Parse
-    let parsed_input = T::parse(&input_text);
-    println!("[Parsed input]");
+    /// Solve part 1, also returning state for part 2 to continue from.
+    /// Override this along with [`Puzzle::solve_part2_carry`] to share work between parts.
+    fn solve_part1_carry(
+        input: &Self::Parsed,
+        params: &Self::Params,
+    ) -> (impl Into<Answer>, Self::Carry) {
+        (Self::solve_part1(input, params), Self::Carry::default())
+    }
 
-    // Solve part 1
-    let part_1_answer = T::solve_part1(parsed_input.clone());
-    println!("[Part 1] {:?}", part_1_answer);
+    /// Solve part 1 from its own copy of the parsed input, also returning state for part 2,
+    /// see [`Puzzle::clone_parsed`]
+    fn solve_part1_carry_owned(
+        input: Self::Parsed,
+        params: &Self::Params,
+    ) -> (impl Into<Answer>, Self::Carry) {
+        let (answer, carry) = Self::solve_part1_carry(&input, params);
+        let answer: Answer = answer.into();
+        (answer, carry)
+    }
 
-    // Solve part 2
-    let part_2_answer = T::solve_part2(parsed_input);
-    println!("[Part 2] {:?}", part_2_answer);
+    /// Solve part 2 using the state handed on by [`Puzzle::solve_part1_carry`]
+    fn solve_part2_carry(
+        input: &Self::Parsed,
+        params: &Self::Params,
+        carry: Self::Carry,
+    ) -> impl Into<Answer> {
+        let _ = carry;
+        Self::solve_part2(input, params)
+    }
 }
@@ -0,0 +1,131 @@
+//! Levelled logging for solvers, filtered per day
+//!
+//! Solvers log through the re-exported [`log`] macros, e.g. `aoc::log::debug!(...)`,
+//! which use the day crate (`day09`, `day09::disk`, ...) as their target.
+//! Logs are written to stderr so they never mix with answers, and only warnings and errors
+//! are shown unless a filter is given with `--log` or the `AOC_LOG` environment variable.
+//!
+//! A filter is a comma separated list of levels, optionally for a target,
+//! e.g. `debug` or `warn,day09=trace`.
+//!
+//! # Examples
+//! ```
+//! use aoc::{log::Level, logging::LogFilter};
+//!
+//! let filter: LogFilter = "warn,day09=trace".parse().unwrap();
+//! assert!(filter.enabled("day09", Level::Trace));
+//! assert!(filter.enabled("day09::disk", Level::Debug));
+//! assert!(!filter.enabled("day06", Level::Info));
+//! assert!(filter.enabled("day06", Level::Warn));
+//! ```
+
+use std::{env, str::FromStr};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// The environment variable read for a filter when none is passed to [`init`]
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// Which log levels are enabled for each target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    default: LevelFilter,
+    /// Sorted longest target first, so the most specific match wins
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            default: LevelFilter::Warn,
+            targets: Vec::new(),
+        }
+    }
+}
+
+impl LogFilter {
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        level <= self.level_for(target)
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .find(|(name, _)| {
+                target
+                    .strip_prefix(name.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// The most verbose level enabled for any target
+    pub fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+impl FromStr for LogFilter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = LogFilter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let (target, level) = match directive.split_once('=') {
+                Some((target, level)) => (Some(target.trim()), level.trim()),
+                None => (None, directive),
+            };
+            let level: LevelFilter = level
+                .parse()
+                .map_err(|_| format!("unknown log level {level:?}"))?;
+            match target {
+                Some(target) => filter.targets.push((target.to_owned(), level)),
+                None => filter.default = level,
+            }
+        }
+
+        filter
+            .targets
+            .sort_by_key(|(target, _)| std::cmp::Reverse(target.len()));
+        Ok(filter)
+    }
+}
+
+struct Logger {
+    filter: LogFilter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.filter.enabled(metadata.target(), metadata.level())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the logger, using `filter` or else the `AOC_LOG` environment variable.
+/// Only the first call has any effect.
+pub fn init(filter: Option<&LogFilter>) {
+    let filter = match (filter, env::var(ENV_VAR)) {
+        (Some(filter), _) => filter.clone(),
+        (None, Ok(spec)) => spec.parse().unwrap_or_else(|err| {
+            eprintln!("warning: ignoring {ENV_VAR}: {err}");
+            LogFilter::default()
+        }),
+        (None, Err(_)) => LogFilter::default(),
+    };
+
+    let max_level = filter.max_level();
+    if log::set_boxed_logger(Box::new(Logger { filter })).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
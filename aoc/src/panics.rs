@@ -0,0 +1,84 @@
+//! Catching panics from puzzle code so that one failing part doesn't stop a run
+//!
+//! # Examples
+//! ```
+//! use aoc::panics;
+//!
+//! assert_eq!(panics::catch(|| 1 + 1), Ok(2));
+//!
+//! let panic = panics::catch(|| -> u32 { panic!("oh no") }).unwrap_err();
+//! assert_eq!(panic.message, "oh no");
+//! assert!(panic.location.is_some());
+//! ```
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+/// A panic caught while running puzzle code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Source location as `file:line:column`, if known
+    pub location: Option<String>,
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Wrap the panic hook so that panics being caught record their location
+/// instead of printing to stderr. Other panics are passed to the original hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let original = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                let location = info.location().map(|l| l.to_string());
+                LOCATION.set(location);
+            } else {
+                original(info);
+            }
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
+}
+
+/// Run `f`, returning the panic it raised, if any
+pub fn catch<R>(f: impl FnOnce() -> R) -> Result<R, Panic> {
+    install_hook();
+
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+
+    result.map_err(|payload| Panic {
+        message: payload_message(payload.as_ref()),
+        location: LOCATION.take(),
+    })
+}
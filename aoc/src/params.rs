@@ -0,0 +1,111 @@
+//! Puzzle parameters that can be overridden from the command line or from examples
+//!
+//! Parameters are declared with [`params!`](crate::params!), and overridden with
+//! `--param name=value`, or `--param dayNN.name=value` to only apply to one day.
+//!
+//! # Examples
+//! ```
+//! use aoc::params::{from_overrides, Params};
+//!
+//! aoc::params! {
+//!     pub struct Blinks {
+//!         /// Blinks for part 1
+//!         pub part1: usize = 25,
+//!         pub part2: usize = 75,
+//!     }
+//! }
+//!
+//! let params: Blinks = from_overrides(11, &["part1=6".to_owned(), "day10.part2=1".to_owned()]).unwrap();
+//! assert_eq!(params, Blinks { part1: 6, part2: 75 });
+//!
+//! let mut params = Blinks::default();
+//! assert!(params.set("part3", "1").is_err());
+//! assert!(params.set("part1", "many").is_err());
+//! ```
+
+/// A set of named parameters with defaults
+pub trait Params: Default + Clone + Send + Sync + 'static {
+    /// Override a parameter from its name and textual value
+    fn set(&mut self, name: &str, value: &str) -> Result<(), String>;
+}
+
+impl Params for () {
+    fn set(&mut self, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter {name:?}, this puzzle has none"))
+    }
+}
+
+/// Apply `name=value` overrides to the default parameters of a day,
+/// skipping those for other days (`dayNN.name=value`)
+pub fn from_overrides<P: Params>(day: u8, overrides: &[String]) -> Result<P, String> {
+    let mut params = P::default();
+    for spec in overrides {
+        let (name, value) = spec
+            .split_once('=')
+            .ok_or_else(|| format!("expected a parameter as name=value, found {spec:?}"))?;
+        let name = match name.split_once('.') {
+            Some((prefix, name)) if prefix == format!("day{day:02}") => name,
+            Some(_) => continue,
+            None => name,
+        };
+        params.set(name.trim(), value.trim())?;
+    }
+    Ok(params)
+}
+
+/// Declare a parameters struct, with a default value for each field.
+///
+/// ```ignore
+/// aoc::params! {
+///     pub struct Params {
+///         /// How many levels can be removed from a report
+///         pub tolerance: usize = 1,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta: meta])*
+        $vis: vis struct $name: ident {
+            $(
+                $(#[$field_meta: meta])*
+                $field_vis: vis $field: ident: $ty: ty = $default: expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+                $(
+                    if name == stringify!($field) {
+                        self.$field = value.parse().map_err(|err| {
+                            format!("invalid value {value:?} for parameter {name}: {err}")
+                        })?;
+                        return Ok(());
+                    }
+                )*
+                Err(format!(
+                    "unknown parameter {name:?}, expected one of: {}",
+                    [$(stringify!($field)),*].join(", ")
+                ))
+            }
+        }
+    };
+}
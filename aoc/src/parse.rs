@@ -0,0 +1,182 @@
+//! Fallible parsing helpers that remember where in the input things went wrong
+//!
+//! Errors are created from the slice of input that caused them, and are later
+//! resolved against the full input text to produce a line, column and snippet.
+//!
+//! # Examples
+//! ```
+//! use aoc::parse;
+//!
+//! let input = "1|2\n3|x\n";
+//! let err = input
+//!     .lines()
+//!     .map(|line| {
+//!         let (a, b) = parse::split_once(line, "|")?;
+//!         Ok((parse::number::<usize>(a)?, parse::number::<usize>(b)?))
+//!     })
+//!     .collect::<parse::ParseResult<Vec<_>>>()
+//!     .unwrap_err()
+//!     .locate(input);
+//!
+//! let location = err.location().unwrap();
+//! assert_eq!((location.line, location.column), (2, 3));
+//! assert_eq!(location.snippet, "3|x");
+//!
+//! // A whole line is underlined without its CRLF line ending
+//! let input = "1|2\r\n";
+//! let err = parse::ParseError::new(&input[..4], "bad line").locate(input);
+//! assert_eq!(err.location().unwrap().width, 3);
+//! ```
+
+use std::{fmt, str::FromStr};
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// An error encountered while parsing puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    span: Option<(usize, usize)>,
+    location: Option<Location>,
+}
+
+/// Where in the input a parse error occured
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-indexed line number
+    pub line: usize,
+    /// 1-indexed column number, counted in chars
+    pub column: usize,
+    /// Number of chars to highlight, starting at the column
+    pub width: usize,
+    /// The full text of the offending line
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// Create an error pointing at the given slice of the input
+    pub fn new(span: &str, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: Some((span.as_ptr() as usize, span.len())),
+            location: None,
+        }
+    }
+
+    /// Create an error that doesn't point at any particular part of the input
+    pub fn msg(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: None,
+            location: None,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The location of the error, if it has been resolved with [`ParseError::locate`]
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    /// Resolve the location of this error against the full input text.
+    /// Has no effect if the error's span did not come from `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let Some((addr, len)) = self.span else {
+            return self;
+        };
+        let start = input.as_ptr() as usize;
+        if addr < start || addr + len > start + input.len() {
+            return self;
+        }
+
+        let offset = addr - start;
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let snippet = input[line_start..line_end].trim_end_matches('\r');
+        let column = input[line_start..offset].chars().count() + 1;
+        // Only underline what's shown of the line, not a trailing `\r`
+        let snippet_end = (line_start + snippet.len()).max(offset);
+        let width = input[offset..(offset + len).min(snippet_end)]
+            .chars()
+            .count()
+            .max(1);
+
+        self.location = Some(Location {
+            line: input[..line_start].matches('\n').count() + 1,
+            column,
+            width,
+            snippet: snippet.to_owned(),
+        });
+        self
+    }
+
+    /// Render the error as a diagnostic for an input read from `path`
+    pub fn report(&self, path: &str) -> String {
+        let Some(location) = &self.location else {
+            return format!("error: {}\n --> {}", self.message, path);
+        };
+
+        let gutter = " ".repeat(location.line.to_string().len());
+        format!(
+            "error: {}\n{gutter}--> {}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            self.message,
+            path,
+            location.line,
+            location.column,
+            location.line,
+            location.snippet,
+            " ".repeat(location.column - 1),
+            "^".repeat(location.width),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}:{}: {}", location.line, location.column, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse a number (or anything else implementing `FromStr`)
+pub fn number<T>(s: &str) -> ParseResult<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.parse()
+        .map_err(|e| ParseError::new(s, format!("invalid number {s:?}: {e}")))
+}
+
+/// Split a string on the first occurence of a delimiter
+pub fn split_once<'a>(s: &'a str, delim: &str) -> ParseResult<(&'a str, &'a str)> {
+    s.split_once(delim)
+        .ok_or_else(|| ParseError::new(s, format!("expected {delim:?}")))
+}
+
+/// Strip a required prefix from a string
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> ParseResult<&'a str> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("expected {prefix:?}")))
+}
+
+/// Parse every char of a string as a base 10 digit
+pub fn digits(s: &str) -> impl Iterator<Item = ParseResult<u32>> + '_ {
+    s.char_indices().map(|(i, c)| {
+        c.to_digit(10).ok_or_else(|| {
+            ParseError::new(
+                &s[i..i + c.len_utf8()],
+                format!("expected digit, found {c:?}"),
+            )
+        })
+    })
+}
@@ -0,0 +1,208 @@
+//! Progress reporting for long running parts
+//!
+//! Progress is drawn as a bar on stderr, but only once the runner has enabled it
+//! (which it does when stderr is a terminal), so it stays quiet in tests and benches.
+//! The runner also silences the bars of parts it gives up on, which carry on running
+//! in the background (see [`silence_thread_when`]).
+//!
+//! # Examples
+//! ```
+//! use aoc::progress::{Progress, ProgressIterator};
+//!
+//! // A known total
+//! let total: u32 = (0..1000).progress("summing").sum();
+//! assert_eq!(total, 499500);
+//!
+//! // An unknown total
+//! let progress = Progress::unknown("searching");
+//! for _ in 0..10 {
+//!     progress.inc(1);
+//! }
+//! assert_eq!(progress.count(), 10);
+//! ```
+
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering::Relaxed},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Silences the bars created on this thread once set
+    static SILENCED: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// How often the bar is redrawn, and how long before it's first drawn
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+const BAR_WIDTH: u64 = 30;
+
+/// Turn drawing of progress bars on or off (it's off by default)
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Relaxed)
+}
+
+/// Stop drawing the progress bars created on this thread from now on, once `silenced`
+/// is set with [`silence`]
+pub fn silence_thread_when(silenced: Arc<AtomicBool>) {
+    SILENCED.with(|current| *current.borrow_mut() = Some(silenced));
+}
+
+/// Set a flag given to [`silence_thread_when`], clearing any bar it left behind.
+/// No bar guarded by the flag is drawn once this returns.
+pub fn silence(silenced: &AtomicBool) {
+    // Bars are drawn while holding stderr, so none can be halfway through drawing
+    let mut stderr = io::stderr().lock();
+    silenced.store(true, Relaxed);
+    if is_enabled() {
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+}
+
+/// Progress through some work, which can be shared between threads
+pub struct Progress {
+    label: String,
+    total: Option<u64>,
+    count: AtomicU64,
+    start: Instant,
+    /// When the bar was last drawn, `None` if it hasn't been yet
+    last_draw: Mutex<Option<Instant>>,
+    /// Set if the work was abandoned, so the bar shouldn't be drawn any more
+    silenced: Option<Arc<AtomicBool>>,
+}
+
+impl Progress {
+    /// Progress towards a known number of steps
+    pub fn new(label: impl Into<String>, total: u64) -> Self {
+        Self::with_total(label.into(), Some(total))
+    }
+
+    /// Progress through an unknown number of steps
+    pub fn unknown(label: impl Into<String>) -> Self {
+        Self::with_total(label.into(), None)
+    }
+
+    fn with_total(label: String, total: Option<u64>) -> Self {
+        Self {
+            label,
+            total,
+            count: AtomicU64::new(0),
+            start: Instant::now(),
+            last_draw: Mutex::new(None),
+            silenced: SILENCED.with(|silenced| silenced.borrow().clone()),
+        }
+    }
+
+    fn is_silenced(&self) -> bool {
+        self.silenced
+            .as_ref()
+            .is_some_and(|silenced| silenced.load(Relaxed))
+    }
+
+    /// Steps completed so far
+    pub fn count(&self) -> u64 {
+        self.count.load(Relaxed)
+    }
+
+    /// Record `steps` more steps completed
+    pub fn inc(&self, steps: u64) {
+        self.count.fetch_add(steps, Relaxed);
+        if !is_enabled() || self.is_silenced() {
+            return;
+        }
+
+        // Skip drawing if another thread is already doing it
+        let Ok(mut last_draw) = self.last_draw.try_lock() else {
+            return;
+        };
+        // Quick work finishes before the bar is ever drawn
+        if last_draw.unwrap_or(self.start).elapsed() < REDRAW_INTERVAL {
+            return;
+        }
+        *last_draw = Some(Instant::now());
+        self.draw();
+    }
+
+    fn draw(&self) {
+        let count = self.count();
+        let elapsed = self.start.elapsed();
+        let line = match self.total {
+            Some(total) => {
+                let filled = (count.min(total) * BAR_WIDTH) / total.max(1);
+                format!(
+                    "{} [{}{}] {count}/{total} ({:.0?})",
+                    self.label,
+                    "#".repeat(filled as usize),
+                    "-".repeat((BAR_WIDTH - filled) as usize),
+                    elapsed
+                )
+            }
+            None => format!("{} {count} ({:.0?})", self.label, elapsed),
+        };
+
+        let mut stderr = io::stderr().lock();
+        // Checked again while holding stderr, see `silence`
+        if self.is_silenced() {
+            return;
+        }
+        let _ = write!(stderr, "\r\x1b[2K{line}");
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for Progress {
+    /// Clear the bar so that it doesn't get mixed up with later output
+    fn drop(&mut self) {
+        // A silenced bar was cleared already, and the line may now be another bar's
+        let drawn = self.last_draw.get_mut().is_ok_and(|last| last.is_some());
+        if drawn && !self.is_silenced() {
+            let _ = write!(io::stderr(), "\r\x1b[2K");
+        }
+    }
+}
+
+/// An iterator that reports progress as items are taken from it
+pub struct WithProgress<I> {
+    iter: I,
+    progress: Progress,
+}
+
+impl<I: Iterator> Iterator for WithProgress<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.progress.inc(1);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub trait ProgressIterator: Iterator + Sized {
+    /// Report progress through this iterator, with a total if its length is known
+    fn progress(self, label: impl Into<String>) -> WithProgress<Self> {
+        let progress = match self.size_hint() {
+            (lower, Some(upper)) if lower == upper => Progress::new(label, upper as u64),
+            _ => Progress::unknown(label),
+        };
+        WithProgress {
+            iter: self,
+            progress,
+        }
+    }
+}
+
+impl<I: Iterator> ProgressIterator for I {}
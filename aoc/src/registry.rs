@@ -0,0 +1,169 @@
+//! A registry of every puzzle so that they can be run from a single binary
+//!
+//! # Examples
+//! ```
+//! use aoc::DaySelection;
+//!
+//! let days: DaySelection = "3..=5".parse().unwrap();
+//! assert!(days.contains(4));
+//! assert!(!days.contains(6));
+//! ```
+
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::{input::Input, ledger::Ledger, Puzzle, PuzzleReport, RunError, RunOptions};
+
+/// A type-erased handle to a puzzle implementation
+#[derive(Clone, Copy)]
+pub struct PuzzleEntry {
+    pub day: u8,
+    pub run: fn(&Input, &RunOptions, Option<&Ledger>) -> Result<PuzzleReport, RunError>,
+    #[cfg(feature = "bench")]
+    pub bench: fn(&mut criterion::Criterion, &str),
+}
+
+impl PuzzleEntry {
+    pub fn of<T: Puzzle>() -> Self {
+        Self {
+            day: T::DAY,
+            run: crate::try_run_puzzle::<T>,
+            #[cfg(feature = "bench")]
+            bench: crate::bench::bench_puzzle::<T>,
+        }
+    }
+}
+
+impl fmt::Debug for PuzzleEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PuzzleEntry(day {})", self.day)
+    }
+}
+
+/// Every known puzzle, ordered by day
+#[derive(Debug, Clone, Default)]
+pub struct Registry(Vec<PuzzleEntry>);
+
+/// Helper for constructing a registry from a list of puzzle types
+#[macro_export]
+macro_rules! registry {
+    ($($puzzle: ty),* $(,)?) => {
+        $crate::Registry::new(vec![$($crate::PuzzleEntry::of::<$puzzle>()),*])
+    };
+}
+
+impl Registry {
+    /// panics if two entries are registered for the same day
+    pub fn new(mut entries: Vec<PuzzleEntry>) -> Self {
+        entries.sort_by_key(|entry| entry.day);
+        if let Some(pair) = entries.windows(2).find(|pair| pair[0].day == pair[1].day) {
+            panic!("Day {} is registered more than once", pair[0].day);
+        }
+        Self(entries)
+    }
+
+    /// Get the puzzle for a given day
+    pub fn get(&self, day: u8) -> Option<&PuzzleEntry> {
+        self.0.iter().find(|entry| entry.day == day)
+    }
+
+    /// Get an iterator over every registered puzzle in day order
+    pub fn iter(&self) -> impl Iterator<Item = &PuzzleEntry> {
+        self.0.iter()
+    }
+
+    /// Get an iterator over the registered puzzles in a selection, in day order
+    pub fn select<'a>(
+        &'a self,
+        selection: &'a DaySelection,
+    ) -> impl Iterator<Item = &'a PuzzleEntry> + 'a {
+        self.0.iter().filter(|entry| selection.contains(entry.day))
+    }
+}
+
+/// A set of days picked on the command line, e.g. `6`, `all`, `3..7`, `3..=7` or `1,4,9`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<RangeInclusive<u8>>),
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Days(ranges) => ranges.iter().any(|range| range.contains(&day)),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+
+        let parse_day = |d: &str| {
+            d.trim()
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid day {d:?} in selection {s:?}"))
+        };
+        let ranges = s
+            .split(',')
+            .map(|part| {
+                if let Some((start, end)) = part.split_once("..=") {
+                    Ok(parse_day(start)?..=parse_day(end)?)
+                } else if let Some((start, end)) = part.split_once("..") {
+                    let end = parse_day(end)?
+                        .checked_sub(1)
+                        .ok_or_else(|| format!("Empty range {part:?} in selection {s:?}"))?;
+                    Ok(parse_day(start)?..=end)
+                } else {
+                    let day = parse_day(part)?;
+                    Ok(day..=day)
+                }
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(DaySelection::Days(ranges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DaySelection;
+
+    #[test]
+    fn parses_day_selections() {
+        let cases = [
+            ("all", DaySelection::All),
+            ("ALL", DaySelection::All),
+            ("6", DaySelection::Days(vec![6..=6])),
+            ("3..7", DaySelection::Days(vec![3..=6])),
+            ("3..=7", DaySelection::Days(vec![3..=7])),
+            ("1,4,9", DaySelection::Days(vec![1..=1, 4..=4, 9..=9])),
+            (" 2, 5..=6 ", DaySelection::Days(vec![2..=2, 5..=6])),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(text.parse(), Ok(expected), "parsing {text:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_bad_day_selections() {
+        for text in ["", "x", "1,,2", "3..", "..=4", "1..0", "256", "-1"] {
+            assert!(
+                text.parse::<DaySelection>().is_err(),
+                "{text:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn selects_days_in_any_range() {
+        let days: DaySelection = "1,4..6".parse().unwrap();
+        let selected: Vec<u8> = (1..=7).filter(|&day| days.contains(day)).collect();
+        assert_eq!(selected, [1, 4, 5]);
+    }
+}
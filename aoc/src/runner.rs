@@ -0,0 +1,982 @@
+//! Running puzzles against an input file and reporting the results
+
+use std::{
+    any::TypeId,
+    fmt,
+    io::{self, IsTerminal},
+    iter,
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use clap::{Args, Parser, ValueEnum};
+
+use crate::{
+    input::{self, Input},
+    json,
+    ledger::{Ledger, LedgerError, Verdict},
+    logging::{self, LogFilter},
+    panics::{self, Panic},
+    params, progress,
+    timing::Timing,
+    Answer, ParseError, Puzzle,
+};
+
+/// Options controlling how a puzzle is run, shared by every runner binary
+#[derive(Debug, Clone, Args)]
+pub struct RunOptions {
+    /// Run each phase this many times and report timing statistics
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub repeat: u32,
+
+    /// Solve part 2 from scratch rather than continuing from part 1's state
+    #[arg(long)]
+    pub independent: bool,
+
+    /// Override a puzzle parameter, or only one day's with `dayNN.name=value`.
+    /// Can't be combined with the answers ledger, which only holds answers for the defaults.
+    #[arg(long = "param", value_name = "NAME=VALUE", conflicts_with_all = ["check", "record"])]
+    pub params: Vec<String>,
+
+    /// Only solve this part (part 2 is then solved from scratch)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Check answers against the answers ledger
+    #[arg(long)]
+    pub check: bool,
+
+    /// Record answers into the answers ledger, replacing existing ones
+    #[arg(long)]
+    pub record: bool,
+
+    /// Path to the answers ledger
+    #[arg(long, default_value = "answers.toml")]
+    pub answers: PathBuf,
+
+    /// Give up on a part that runs longer than this, e.g. `30`, `2.5s`, `500ms` or `1m`.
+    /// Applies to each run when repeating.
+    #[arg(long, value_parser = parse_duration)]
+    pub timeout: Option<Duration>,
+
+    /// Show solver logs on stderr, e.g. `debug` or `warn,day09=trace` (overrides `AOC_LOG`)
+    #[arg(long, value_name = "FILTER")]
+    pub log: Option<LogFilter>,
+
+    /// How to print results
+    #[arg(long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+
+    /// Directory to look for an input named `dayNN.txt` in (before `AOC_INPUT_DIR` and `./input`)
+    #[arg(long)]
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable lines like `[Part 1] 1234`
+    #[default]
+    Text,
+    /// One JSON object per line for each part, or for each day that couldn't be run
+    Json,
+}
+
+/// Parse a duration in seconds, or with a `ms`, `s` or `m` suffix
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (number, scale) = if let Some(ms) = s.strip_suffix("ms") {
+        (ms, 0.001)
+    } else if let Some(secs) = s.strip_suffix('s') {
+        (secs, 1.0)
+    } else if let Some(mins) = s.strip_suffix('m') {
+        (mins, 60.0)
+    } else {
+        (s, 1.0)
+    };
+
+    let number: f64 = number
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid duration {s:?}"))?;
+    Duration::try_from_secs_f64(number * scale)
+        .map_err(|err| format!("invalid duration {s:?}: {err}"))
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            repeat: 1,
+            independent: false,
+            params: Vec::new(),
+            part: None,
+            check: false,
+            record: false,
+            answers: PathBuf::from("answers.toml"),
+            timeout: None,
+            log: None,
+            format: OutputFormat::Text,
+            input_dir: None,
+        }
+    }
+}
+
+impl RunOptions {
+    /// Set up logging, and progress bars when printing text to a terminal
+    pub fn init_output(&self) {
+        logging::init(self.log.as_ref());
+        progress::set_enabled(self.format == OutputFormat::Text && io::stderr().is_terminal());
+    }
+
+    /// Read the input for a day, from `path` if given or else by searching the input directories
+    pub fn read_input(&self, day: u8, path: Option<&str>) -> Result<Input, RunError> {
+        Input::read(day, path, self.input_dir.as_deref())
+    }
+
+    /// Report an error that stopped a day from being run
+    pub fn report_error(&self, day: u8, err: &RunError) {
+        match self.format {
+            OutputFormat::Text => eprintln!("{err}"),
+            OutputFormat::Json => json::print_error(day, err),
+        }
+    }
+
+    /// Load the answers ledger if checking or recording answers
+    pub fn load_ledger(&self) -> Result<Option<Ledger>, RunError> {
+        if !(self.check || self.record) {
+            return Ok(None);
+        }
+
+        Ledger::load(&self.answers)
+            .map(Some)
+            .map_err(|source| self.ledger_error(source))
+    }
+
+    /// Save the answers ledger if recording answers
+    pub fn save_ledger(&self, ledger: &Ledger) -> Result<(), RunError> {
+        if !self.record {
+            return Ok(());
+        }
+
+        ledger
+            .save(&self.answers)
+            .map_err(|source| self.ledger_error(source))?;
+        if self.format == OutputFormat::Text {
+            println!("[Recorded answers to {}]", self.answers.display());
+        }
+        Ok(())
+    }
+
+    fn ledger_error(&self, source: LedgerError) -> RunError {
+        RunError::Ledger {
+            path: self.answers.display().to_string(),
+            source,
+        }
+    }
+}
+
+/// Command line arguments for a single-day binary
+#[derive(Parser)]
+struct PuzzleCli {
+    /// Paths to puzzle inputs, or `-` to read stdin.
+    /// Searched for in the input directories when not given,
+    /// and compared in a table when there are several.
+    inputs: Vec<String>,
+
+    #[command(flatten)]
+    options: RunOptions,
+}
+
+/// An error that prevented a puzzle from being run
+#[derive(Debug)]
+pub enum RunError {
+    Read {
+        path: String,
+        source: io::Error,
+    },
+    /// No input path was given and none of the search paths exist
+    NoInput {
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    Parse {
+        path: String,
+        source: ParseError,
+    },
+    Ledger {
+        path: String,
+        source: LedgerError,
+    },
+    /// A `--param` override was invalid for the puzzle
+    Params {
+        day: u8,
+        message: String,
+    },
+    /// The puzzle panicked while parsing its input
+    ParsePanic {
+        path: String,
+        panic: Panic,
+    },
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Read { path, source } => {
+                write!(f, "error: can't read AOC input file {path}: {source}")
+            }
+            RunError::NoInput { day, tried } => {
+                writeln!(f, "error: can't find an AOC input for day {day:02}, tried")?;
+                for path in tried {
+                    writeln!(f, "  {}", path.display())?;
+                }
+                write!(
+                    f,
+                    "pass a path (or `-` for stdin), or set {}",
+                    input::INPUT_DIR_VAR
+                )
+            }
+            RunError::Parse { path, source } => write!(f, "{}", source.report(path)),
+            RunError::Ledger { path, source } => {
+                write!(f, "error: can't use answers ledger {path}: {source}")
+            }
+            RunError::Params { day, message } => {
+                write!(f, "error: bad parameters for day {day:02}: {message}")
+            }
+            RunError::ParsePanic { path, panic } => {
+                write!(f, "error: parsing {path} {panic}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+/// The outcome of running a puzzle against an input
+#[derive(Debug, Clone)]
+pub struct PuzzleReport {
+    pub day: u8,
+    pub input_path: String,
+    pub parse_timing: Timing,
+    pub parts: Vec<PartReport>,
+}
+
+/// The outcome of solving one part of a puzzle
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    /// The answer, or why the part couldn't produce one
+    pub answer: Result<Answer, PartFailure>,
+    /// Only present when the part produced an answer
+    pub timing: Option<Timing>,
+    /// Only present when checking an answer against the ledger
+    pub verdict: Option<Verdict>,
+}
+
+/// Why a part failed to produce an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartFailure {
+    Panicked(Panic),
+    /// The part was still running when its time budget ran out
+    TimedOut(Duration),
+}
+
+impl fmt::Display for PartFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartFailure::Panicked(panic) => write!(f, "FAILED: {panic}"),
+            PartFailure::TimedOut(budget) => write!(f, "TIMEOUT: still running after {budget:.2?}"),
+        }
+    }
+}
+
+/// Worker threads of timed out phases that were left running
+static ABANDONED: AtomicUsize = AtomicUsize::new(0);
+
+/// How many timed out parts were left running on worker threads
+pub fn abandoned_workers() -> usize {
+    ABANDONED.load(Ordering::Relaxed)
+}
+
+/// Exit the process if any timed out parts are still running on worker threads,
+/// warning that they may have slowed down the parts that ran after them.
+/// Called by the runners once everything has been run.
+pub fn exit_if_abandoned() {
+    let abandoned = abandoned_workers();
+    if abandoned > 0 {
+        eprintln!(
+            "warning: {abandoned} timed out part(s) were still running in the background, \
+             timings of the parts after them may be skewed"
+        );
+        process::exit(1);
+    }
+}
+
+/// What a phase's worker thread reports back
+enum WorkerEvent<R> {
+    /// Another run of the phase has started, so its time budget starts again
+    RunStarted,
+    Finished(Result<R, Panic>),
+}
+
+/// Run a phase of a part, catching panics.
+///
+/// With a time budget the phase runs on a worker thread, and `f` must call the
+/// function it's given at the start of each run, as the budget applies to each run.
+/// The worker thread is abandoned if a run doesn't finish in time. There's no way
+/// to stop it, so it keeps using CPU and memory in the background until the
+/// process exits (see [`exit_if_abandoned`]).
+fn run_phase<R: Send + 'static>(
+    name: String,
+    budget: Option<Duration>,
+    f: impl FnOnce(&dyn Fn()) -> R + Send + 'static,
+) -> Result<R, PartFailure> {
+    let Some(budget) = budget else {
+        return panics::catch(|| f(&|| ())).map_err(PartFailure::Panicked);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let silenced = Arc::new(AtomicBool::new(false));
+    let worker_silenced = silenced.clone();
+    thread::Builder::new()
+        .name(name)
+        .spawn(move || {
+            progress::silence_thread_when(worker_silenced);
+            // The receiver is gone if we timed out, so nobody wants to hear from us
+            let run_started = || {
+                let _ = sender.send(WorkerEvent::RunStarted);
+            };
+            let result = panics::catch(|| f(&run_started));
+            let _ = sender.send(WorkerEvent::Finished(result));
+        })
+        .expect("failed to spawn worker thread");
+
+    loop {
+        match receiver.recv_timeout(budget) {
+            Ok(WorkerEvent::RunStarted) => continue,
+            Ok(WorkerEvent::Finished(result)) => return result.map_err(PartFailure::Panicked),
+            Err(RecvTimeoutError::Timeout) => {
+                ABANDONED.fetch_add(1, Ordering::Relaxed);
+                progress::silence(&silenced);
+                return Err(PartFailure::TimedOut(budget));
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(PartFailure::Panicked(Panic {
+                    message: "worker thread exited without a result".to_owned(),
+                    location: None,
+                }))
+            }
+        }
+    }
+}
+
+impl PartReport {
+    /// Print the report as a line like `[Part 1] 1234 (1.20ms)`
+    fn print(&self) {
+        println!("{}", self.describe());
+    }
+
+    /// The report as printed, with a multi-line answer below a label line holding
+    /// the timing and verdict
+    fn describe(&self) -> String {
+        let mut line = format!("[Part {}]", self.part);
+        match &self.answer {
+            Ok(Answer::Grid(_)) => {}
+            Ok(answer) => line += &format!(" {answer}"),
+            Err(failure) => line += &format!(" {failure}"),
+        }
+        if let Some(timing) = &self.timing {
+            line += &format!(" ({})", describe_timing(timing));
+        }
+        if let Some(verdict) = &self.verdict {
+            line += &format!(" {verdict}");
+        }
+        if let Ok(Answer::Grid(grid)) = &self.answer {
+            line += &format!("\n{grid}");
+        }
+        line
+    }
+
+    /// Whether the part failed to produce an answer or disagreed with the ledger
+    pub fn failed(&self) -> bool {
+        self.answer.is_err() || matches!(self.verdict, Some(Verdict::Fail { .. }))
+    }
+}
+
+impl PuzzleReport {
+    /// Whether any part failed to produce an answer or disagreed with the ledger
+    pub fn failed(&self) -> bool {
+        self.parts.iter().any(PartReport::failed)
+    }
+
+    /// Record every answer in this report into a ledger, skipping unsolved parts
+    pub fn record_into(&self, ledger: &mut Ledger) {
+        for part in &self.parts {
+            match &part.answer {
+                Ok(Answer::Unsolved) | Err(_) => {}
+                Ok(answer) => ledger.record(self.day, part.part, answer.to_string()),
+            }
+        }
+    }
+}
+
+/// Solve a part from the puzzle's own copy of the input if it opted in to one
+/// (see [`Puzzle::clone_parsed`]), otherwise from the shared input
+pub(crate) fn solve<T: Puzzle>(
+    part: u8,
+    input: &T::Parsed,
+    params: &T::Params,
+    owned: Option<T::Parsed>,
+) -> Answer {
+    match (part, owned) {
+        (1, Some(owned)) => T::solve_part1_owned(owned, params).into(),
+        (1, None) => T::solve_part1(input, params).into(),
+        (_, Some(owned)) => T::solve_part2_owned(owned, params).into(),
+        (_, None) => T::solve_part2(input, params).into(),
+    }
+}
+
+/// Solve part 1 for part 2 to carry on from, like [`solve`]
+pub(crate) fn solve_carry<T: Puzzle>(
+    input: &T::Parsed,
+    params: &T::Params,
+    owned: Option<T::Parsed>,
+) -> (Answer, T::Carry) {
+    match owned {
+        Some(owned) => {
+            let (answer, carry) = T::solve_part1_carry_owned(owned, params);
+            (answer.into(), carry)
+        }
+        None => {
+            let (answer, carry) = T::solve_part1_carry(input, params);
+            (answer.into(), carry)
+        }
+    }
+}
+
+/// Whether part 1 hands anything on to part 2, rather than using `()`
+fn carries<T: Puzzle>() -> bool {
+    TypeId::of::<T::Carry>() != TypeId::of::<()>()
+}
+
+/// The timing of a phase, followed by its allocations if they were counted
+fn describe_timing(timing: &Timing) -> String {
+    match timing.allocs() {
+        Some(allocs) => format!("{timing}; {allocs}"),
+        None => timing.to_string(),
+    }
+}
+
+pub fn run_puzzle<T: Puzzle>() {
+    let cli = PuzzleCli::parse();
+    let inputs: Vec<&str> = cli.inputs.iter().map(String::as_str).collect();
+    run_puzzle_with_options::<T>(&inputs, &cli.options)
+}
+
+/// Run a puzzle with default options, giving up on any part that takes longer than `timeout`
+pub fn run_puzzle_with_path<T: Puzzle>(input_path: &str, timeout: Option<Duration>) {
+    let options = RunOptions {
+        timeout,
+        ..RunOptions::default()
+    };
+    run_puzzle_with_options::<T>(&[input_path], &options)
+}
+
+/// Run a puzzle against each input path, or against an input found by searching
+/// the input directories if there are none.
+/// Several inputs are compared in a table at the end.
+pub fn run_puzzle_with_options<T: Puzzle>(input_paths: &[&str], options: &RunOptions) {
+    options.init_output();
+    if input_paths.len() > 1 {
+        if options.check || options.record {
+            eprintln!("error: answers can only be checked or recorded for a single input");
+            process::exit(1);
+        }
+        return run_puzzle_with_inputs::<T>(input_paths, options);
+    }
+
+    let result = options.load_ledger().and_then(|mut ledger| {
+        let input = options.read_input(T::DAY, input_paths.first().copied())?;
+        let report = try_run_puzzle::<T>(&input, options, ledger.as_ref())?;
+        if let Some(ledger) = &mut ledger {
+            report.record_into(ledger);
+            options.save_ledger(ledger)?;
+        }
+        Ok(report)
+    });
+    if let Err(err) = &result {
+        options.report_error(T::DAY, err);
+    }
+    exit_if_abandoned();
+
+    match result {
+        Ok(report) if report.failed() => process::exit(1),
+        Ok(_) => {}
+        Err(_) => process::exit(1),
+    }
+}
+
+/// Run a puzzle against several inputs, without a ledger, then print a table comparing them
+fn run_puzzle_with_inputs<T: Puzzle>(input_paths: &[&str], options: &RunOptions) {
+    let mut rows = Vec::new();
+    for path in input_paths {
+        let result = options
+            .read_input(T::DAY, Some(path))
+            .and_then(|input| try_run_puzzle::<T>(&input, options, None));
+        if let Err(err) = &result {
+            options.report_error(T::DAY, err);
+        }
+        rows.push((path.to_string(), result));
+    }
+
+    if options.format == OutputFormat::Text {
+        print_table(&rows);
+    }
+    exit_if_abandoned();
+    if rows
+        .iter()
+        .any(|(_, result)| result.as_ref().map_or(true, PuzzleReport::failed))
+    {
+        process::exit(1);
+    }
+}
+
+/// Print the answers for each input side by side, e.g.
+/// ```text
+/// input      | part 1           | part 2
+/// -----------+------------------+-----------------
+/// sample.txt | 41 (12.50µs)     | 6 (1.02ms)
+/// input.txt  | 4711 (301.22µs)  | TIMEOUT
+/// ```
+fn print_table(rows: &[(String, Result<PuzzleReport, RunError>)]) {
+    println!();
+    print!("{}", format_table(rows));
+}
+
+/// A table of each input's answers, one row per input
+fn format_table(rows: &[(String, Result<PuzzleReport, RunError>)]) -> String {
+    let header = ["input".to_owned(), "part 1".to_owned(), "part 2".to_owned()];
+    let table: Vec<[String; 3]> = rows
+        .iter()
+        .map(|(path, result)| [path.clone(), table_cell(result, 1), table_cell(result, 2)])
+        .collect();
+
+    let widths: Vec<usize> = (0..3)
+        .map(|column| {
+            iter::once(&header)
+                .chain(&table)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let format_row = |row: &[String; 3]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_owned()
+    };
+
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>()
+        .join("-+-");
+    iter::once(format_row(&header))
+        .chain(iter::once(separator))
+        .chain(table.iter().map(format_row))
+        .map(|line| line + "\n")
+        .collect()
+}
+
+fn table_cell(result: &Result<PuzzleReport, RunError>, part: u8) -> String {
+    let Ok(report) = result else {
+        return "ERROR".to_owned();
+    };
+    let Some(part) = report.parts.iter().find(|report| report.part == part) else {
+        return "-".to_owned();
+    };
+
+    match (&part.answer, &part.timing) {
+        (Ok(Answer::Grid(_)), _) => "(grid)".to_owned(),
+        (Ok(answer), Some(timing)) => format!("{answer} ({:.2?})", timing.min()),
+        (Ok(answer), None) => answer.to_string(),
+        (Err(PartFailure::Panicked(_)), _) => "FAILED".to_owned(),
+        (Err(PartFailure::TimedOut(_)), _) => "TIMEOUT".to_owned(),
+    }
+}
+
+/// Run a puzzle, returning any error that stopped it from being solved
+/// rather than exiting the process.
+///
+/// Answers are checked against the ledger when `options.check` is set,
+/// and parts that exceed `options.timeout` are reported as timed out.
+pub fn try_run_puzzle<T: Puzzle>(
+    input: &Input,
+    options: &RunOptions,
+    ledger: Option<&Ledger>,
+) -> Result<PuzzleReport, RunError> {
+    let repeat = options.repeat as usize;
+    let budget = options.timeout;
+    let phase_name = |part: u8| format!("day{:02}-part{part}", T::DAY);
+    let ledger = ledger.filter(|_| options.check);
+    let text = options.format == OutputFormat::Text;
+
+    let input_path = &input.source;
+    let params: T::Params =
+        params::from_overrides(T::DAY, &options.params).map_err(|message| RunError::Params {
+            day: T::DAY,
+            message,
+        })?;
+    if text {
+        println!("[Read {}]", input_path);
+    }
+
+    // Parse
+    let (parsed_input, parse_timing) =
+        panics::catch(|| Timing::measure(repeat, || (), |_| T::parse(&input.text))).map_err(
+            |panic| RunError::ParsePanic {
+                path: input_path.to_owned(),
+                panic,
+            },
+        )?;
+    let parsed_input = parsed_input.map_err(|err| RunError::Parse {
+        path: input_path.to_owned(),
+        source: err.locate(&input.text),
+    })?;
+    if text {
+        println!("[Parsed input] ({})", describe_timing(&parse_timing));
+    }
+
+    let parsed_input = Arc::new(parsed_input);
+    let params = Arc::new(params);
+
+    let part_report = |part: u8, result: Result<(Answer, Timing), PartFailure>| {
+        let (answer, timing) = match result {
+            Ok((answer, timing)) => (Ok(answer), Some(timing)),
+            Err(failure) => (Err(failure), None),
+        };
+        let verdict = match (&answer, ledger) {
+            (Ok(answer), Some(ledger)) => Some(ledger.check(T::DAY, part, answer)),
+            _ => None,
+        };
+
+        let report = PartReport {
+            part,
+            answer,
+            timing,
+            verdict,
+        };
+        if text {
+            report.print();
+        }
+        report
+    };
+
+    // Any copy of the input the puzzle wants to own is made outside of the timed region
+    let solve_part = |part: u8| {
+        let parsed_input = parsed_input.clone();
+        let params = params.clone();
+        run_phase(phase_name(part), budget, move |run_started| {
+            Timing::measure(
+                repeat,
+                || {
+                    run_started();
+                    T::clone_parsed(&parsed_input)
+                },
+                |owned| solve::<T>(part, &parsed_input, &params, owned),
+            )
+        })
+    };
+
+    let parts = if let Some(part) = options.part {
+        match part {
+            1 => vec![part_report(1, solve_part(1))],
+            _ => vec![part_report(2, solve_part(2))],
+        }
+    } else if options.independent || !carries::<T>() {
+        let part_1 = part_report(1, solve_part(1));
+        vec![part_1, part_report(2, solve_part(2))]
+    } else {
+        // Solve part 1, keeping what it hands on to part 2
+        let input = parsed_input.clone();
+        let part1_params = params.clone();
+        let result = run_phase(phase_name(1), budget, move |run_started| {
+            Timing::measure(
+                repeat,
+                || {
+                    run_started();
+                    T::clone_parsed(&input)
+                },
+                |owned| solve_carry::<T>(&input, &part1_params, owned),
+            )
+        });
+        let (result, carry) = match result {
+            Ok(((answer, carry), timing)) => (Ok((answer, timing)), Some(carry)),
+            Err(failure) => (Err(failure), None),
+        };
+        let part_1 = part_report(1, result);
+
+        // Solve part 2, each run starting from its own (untimed) clone of the carry.
+        // If part 1 failed there's nothing to carry, so solve part 2 from scratch.
+        let result = match carry {
+            Some(carry) => {
+                let input = parsed_input.clone();
+                let params = params.clone();
+                run_phase(phase_name(2), budget, move |run_started| {
+                    Timing::measure(
+                        repeat,
+                        || {
+                            run_started();
+                            carry.clone()
+                        },
+                        |carry| T::solve_part2_carry(&input, &params, carry).into(),
+                    )
+                })
+            }
+            None => solve_part(2),
+        };
+        vec![part_1, part_report(2, result)]
+    };
+
+    let report = PuzzleReport {
+        day: T::DAY,
+        input_path: input_path.to_owned(),
+        parse_timing,
+        parts,
+    };
+    if options.format == OutputFormat::Json {
+        json::print_report(&report);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+    use crate::{ledger::Verdict, ParseResult};
+
+    /// Sums numbers in part 1 and doubles the sum in part 2, which sleeps for the
+    /// largest number of milliseconds first. Part 2 panics on a negative number,
+    /// and never finishes if there's a zero.
+    struct Sums;
+
+    impl Sums {
+        fn double(input: &[i64], sum: i64) -> i64 {
+            if let Some(n) = input.iter().find(|&&n| n < 0) {
+                panic!("can't double {n}");
+            }
+            if input.contains(&0) {
+                loop {
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+            let longest = input.iter().max().copied().unwrap_or(0);
+            thread::sleep(Duration::from_millis(longest as u64));
+            sum * 2
+        }
+    }
+
+    impl Puzzle for Sums {
+        const DAY: u8 = 1;
+
+        type Parsed = Vec<i64>;
+        type Carry = i64;
+        type Params = ();
+
+        fn parse(input: &str) -> ParseResult<Self::Parsed> {
+            input.split_whitespace().map(crate::parse::number).collect()
+        }
+
+        fn solve_part1(input: &Self::Parsed, _params: &()) -> impl Into<Answer> {
+            input.iter().sum::<i64>()
+        }
+
+        fn solve_part2(input: &Self::Parsed, _params: &()) -> impl Into<Answer> {
+            Self::double(input, input.iter().sum())
+        }
+
+        fn solve_part1_carry(input: &Self::Parsed, _params: &()) -> (impl Into<Answer>, i64) {
+            let sum = input.iter().sum();
+            (sum, sum)
+        }
+
+        fn solve_part2_carry(input: &Self::Parsed, _params: &(), sum: i64) -> impl Into<Answer> {
+            Self::double(input, sum)
+        }
+    }
+
+    fn run(text: &str, options: &RunOptions) -> Result<PuzzleReport, RunError> {
+        let input = Input {
+            source: "test".to_owned(),
+            text: text.to_owned(),
+        };
+        try_run_puzzle::<Sums>(&input, options, None)
+    }
+
+    fn answers(report: &PuzzleReport) -> Vec<Result<Answer, PartFailure>> {
+        report
+            .parts
+            .iter()
+            .map(|part| part.answer.clone())
+            .collect()
+    }
+
+    #[test]
+    fn solves_each_part_with_and_without_carrying() {
+        for independent in [false, true] {
+            let options = RunOptions {
+                independent,
+                ..Default::default()
+            };
+            let report = run("1 2 3", &options).unwrap();
+            assert_eq!(
+                answers(&report),
+                [Ok(Answer::from(6)), Ok(Answer::from(12))]
+            );
+            assert!(!report.failed());
+        }
+    }
+
+    #[test]
+    fn a_panicking_part_does_not_stop_the_other() {
+        let timeouts = [None, Some(Duration::from_secs(10))];
+        for (independent, timeout) in [false, true].into_iter().zip(timeouts) {
+            let options = RunOptions {
+                independent,
+                timeout,
+                ..Default::default()
+            };
+            let report = run("1 -2", &options).unwrap();
+            assert_eq!(report.parts[0].answer, Ok(Answer::from(-1)));
+            match &report.parts[1].answer {
+                Err(PartFailure::Panicked(panic)) => assert_eq!(panic.message, "can't double -2"),
+                other => panic!("expected part 2 to panic, got {other:?}"),
+            }
+            assert!(report.parts[1].timing.is_none());
+            assert!(report.failed());
+        }
+    }
+
+    #[test]
+    fn a_part_that_never_finishes_times_out() {
+        let abandoned = abandoned_workers();
+        let options = RunOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let report = run("0 1", &options).unwrap();
+        assert_eq!(report.parts[0].answer, Ok(Answer::from(1)));
+        assert_eq!(
+            report.parts[1].answer,
+            Err(PartFailure::TimedOut(Duration::from_millis(50)))
+        );
+        assert!(abandoned_workers() > abandoned);
+    }
+
+    #[test]
+    fn the_timeout_applies_to_each_run() {
+        // Five runs of 20ms each would overrun a budget for all of them
+        let options = RunOptions {
+            repeat: 5,
+            timeout: Some(Duration::from_millis(60)),
+            ..Default::default()
+        };
+        let report = run("20", &options).unwrap();
+        assert_eq!(report.parts[1].answer, Ok(Answer::from(40)));
+        assert_eq!(report.parts[1].timing.as_ref().unwrap().runs(), 5);
+    }
+
+    #[test]
+    fn parse_errors_stop_the_run() {
+        let err = run("1 x", &RunOptions::default()).unwrap_err();
+        match err {
+            RunError::Parse { path, source } => {
+                assert_eq!(path, "test");
+                assert_eq!(
+                    source.to_string(),
+                    "1:3: invalid number \"x\": invalid digit found in string"
+                );
+            }
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_durations() {
+        let cases = [
+            ("30", Some(Duration::from_secs(30))),
+            ("2.5s", Some(Duration::from_millis(2500))),
+            ("500ms", Some(Duration::from_millis(500))),
+            ("1m", Some(Duration::from_secs(60))),
+            (" 3 s", Some(Duration::from_secs(3))),
+            ("", None),
+            ("ms", None),
+            ("10us", None),
+            ("-1s", None),
+            ("fast", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(parse_duration(text).ok(), expected, "parsing {text:?}");
+        }
+    }
+
+    #[test]
+    fn tabulates_the_answers_for_each_input() {
+        let report = PuzzleReport {
+            day: 1,
+            input_path: "a.txt".to_owned(),
+            parse_timing: Timing::measure(1, || (), |_| ()).1,
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    answer: Ok(Answer::from(6)),
+                    timing: None,
+                    verdict: None,
+                },
+                PartReport {
+                    part: 2,
+                    answer: Err(PartFailure::TimedOut(Duration::from_secs(1))),
+                    timing: None,
+                    verdict: None,
+                },
+            ],
+        };
+        let error = RunError::Params {
+            day: 1,
+            message: "bad".to_owned(),
+        };
+        let rows = [
+            ("a.txt".to_owned(), Ok(report)),
+            ("longer.txt".to_owned(), Err(error)),
+        ];
+        assert_eq!(
+            format_table(&rows),
+            "input      | part 1 | part 2\n\
+             -----------+--------+--------\n\
+             a.txt      | 6      | TIMEOUT\n\
+             longer.txt | ERROR  | ERROR\n"
+        );
+    }
+
+    #[test]
+    fn prints_grid_answers_below_the_label_line() {
+        let report = PartReport {
+            part: 2,
+            answer: Ok(Answer::Grid("#.\n.#".to_owned())),
+            timing: None,
+            verdict: Some(Verdict::Pass),
+        };
+        assert_eq!(report.describe(), "[Part 2] PASS\n#.\n.#");
+    }
+}
@@ -0,0 +1,142 @@
+//! Timing of puzzle phases, optionally repeated to get a distribution
+//!
+//! # Examples
+//! ```
+//! use std::time::Duration;
+//! use aoc::timing::Timing;
+//!
+//! let timing = Timing::from_samples(vec![
+//!     Duration::from_millis(3),
+//!     Duration::from_millis(1),
+//!     Duration::from_millis(2),
+//! ]);
+//! assert_eq!(timing.min(), Duration::from_millis(1));
+//! assert_eq!(timing.median(), Duration::from_millis(2));
+//! assert_eq!(timing.mean(), Duration::from_millis(2));
+//! assert_eq!(timing.stddev(), Duration::from_millis(1));
+//! ```
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::alloc::{self, AllocStats};
+
+/// Durations recorded for each run of a phase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    samples: Vec<Duration>,
+    allocs: Option<AllocStats>,
+}
+
+impl Timing {
+    /// panics if there are no samples
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Timing needs at least one sample");
+        samples.sort();
+        Self {
+            samples,
+            allocs: None,
+        }
+    }
+
+    /// Run `f` `repeat` times (at least once), timing each run and returning the last result.
+    /// The value passed to `f` is created by `setup` outside of the timed region.
+    ///
+    /// If the counting allocator is installed, the allocations of the first run are recorded too.
+    pub fn measure<I, R>(
+        repeat: usize,
+        mut setup: impl FnMut() -> I,
+        mut f: impl FnMut(I) -> R,
+    ) -> (R, Self) {
+        let mut samples = Vec::with_capacity(repeat.max(1));
+        let mut allocs = None;
+        let mut result = None;
+        for run in 0..repeat.max(1) {
+            let input = setup();
+            let start = Instant::now();
+            let output = if run == 0 {
+                let (output, stats) = alloc::measure(|| f(input));
+                allocs = stats;
+                output
+            } else {
+                f(input)
+            };
+            samples.push(start.elapsed());
+            result = Some(output);
+        }
+
+        let mut timing = Self::from_samples(samples);
+        timing.allocs = allocs;
+        (result.unwrap(), timing)
+    }
+
+    /// Allocations made by a single run, if the counting allocator is installed
+    pub fn allocs(&self) -> Option<&AllocStats> {
+        self.allocs.as_ref()
+    }
+
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    pub fn runs(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    pub fn median(&self) -> Duration {
+        let mid = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[mid - 1] + self.samples[mid]) / 2
+        } else {
+            self.samples[mid]
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    /// Sample standard deviation (zero for a single run)
+    pub fn stddev(&self) -> Duration {
+        if self.samples.len() < 2 {
+            return Duration::ZERO;
+        }
+
+        let mean = self.mean().as_secs_f64();
+        let variance = self
+            .samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / (self.samples.len() - 1) as f64;
+        Duration::from_secs_f64(variance.sqrt())
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.runs() == 1 {
+            return write!(f, "{:.2?}", self.min());
+        }
+
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, mean {:.2?} ± {:.2?} over {} runs",
+            self.min(),
+            self.median(),
+            self.mean(),
+            self.stddev(),
+            self.runs()
+        )
+    }
+}
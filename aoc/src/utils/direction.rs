@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Dir {
     North,
@@ -54,3 +56,19 @@ impl Dir {
         }
     }
 }
+
+impl FromStr for Dir {
+    type Err = String;
+
+    /// Parse a direction name (`north`, `n`, ...) or arrow (`^`, `>`, `v`, `<`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dir::*;
+        match s.to_ascii_lowercase().as_str() {
+            "north" | "n" | "up" | "^" => Ok(North),
+            "east" | "e" | "right" | ">" => Ok(East),
+            "south" | "s" | "down" | "v" => Ok(South),
+            "west" | "w" | "left" | "<" => Ok(West),
+            _ => Err(format!("unknown direction {s:?}")),
+        }
+    }
+}
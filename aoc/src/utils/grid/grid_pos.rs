@@ -19,7 +19,7 @@ macro_rules! pos {
 
 impl GridPos {
     /// Get cartesian neighbours of this position
-    /// (not guaranteed to be in bounds)
+    /// (not guaranteed to be in bounds, see [`Grid::neighbours`] for that)
     pub fn neighbours(&self) -> impl Iterator<Item = Self> {
         let (x, y) = (self.0, self.1);
         [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
@@ -29,7 +29,8 @@ impl GridPos {
 
     /// Whether this position is in bounds for a given grid
     pub fn in_grid<T>(&self, grid: &Grid<T>) -> bool {
-        (0..grid.width as isize).contains(&self.0) && (0..grid.height as isize).contains(&self.1)
+        (0..grid.width() as isize).contains(&self.0)
+            && (0..grid.height() as isize).contains(&self.1)
     }
 }
 
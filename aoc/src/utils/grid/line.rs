@@ -0,0 +1,116 @@
+//! Rays and lines of positions, for line of sight style queries
+
+use std::iter;
+
+use super::{Grid, GridPos};
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl GridPos {
+    /// The smallest step in the same direction as this offset, that lands on every
+    /// grid position along it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::{pos, utils::grid::GridPos};
+    /// assert_eq!(pos!(4, -6).reduced(), pos!(2, -3));
+    /// assert_eq!(pos!(0, 5).reduced(), pos!(0, 1));
+    /// assert_eq!(pos!(0, 0).reduced(), pos!(0, 0));
+    /// ```
+    pub fn reduced(self) -> GridPos {
+        match gcd(self.0.unsigned_abs(), self.1.unsigned_abs()) as isize {
+            0 => self,
+            divisor => GridPos(self.0 / divisor, self.1 / divisor),
+        }
+    }
+
+    /// Every position from this one onwards, taking `step` each time (never ends)
+    pub fn ray(self, step: GridPos) -> impl Iterator<Item = GridPos> {
+        iter::successors(Some(self), move |&pos| Some(pos + step))
+    }
+
+    /// Every position from this one onwards, taking `step` each time until leaving the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::{pos, utils::grid::{Grid, GridPos}};
+    /// let grid: Grid<u8> = Grid::from_default(4, 4).unwrap();
+    /// let ray: Vec<_> = pos!(0, 1).ray_in(&grid, pos!(2, 1)).collect();
+    /// assert_eq!(ray, [pos!(0, 1), pos!(2, 2)]);
+    /// ```
+    pub fn ray_in<'a, T>(
+        self,
+        grid: &'a Grid<T>,
+        step: GridPos,
+    ) -> impl Iterator<Item = GridPos> + 'a {
+        assert_ne!(step, GridPos(0, 0), "a ray needs a non-zero step");
+        self.ray(step).take_while(|pos| pos.in_grid(grid))
+    }
+
+    /// Every grid position exactly in line with this position and `other`, in both
+    /// directions, starting from the edge of the grid behind this position and heading
+    /// towards `other`. Empty if this position isn't in the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::{pos, utils::grid::{Grid, GridPos}};
+    /// let grid: Grid<u8> = Grid::from_default(7, 7).unwrap();
+    /// let line: Vec<_> = pos!(3, 3).line_through(pos!(5, 1), &grid).collect();
+    /// assert_eq!(line, [pos!(0, 6), pos!(1, 5), pos!(2, 4), pos!(3, 3), pos!(4, 2), pos!(5, 1), pos!(6, 0)]);
+    /// ```
+    pub fn line_through<'a, T>(
+        self,
+        other: GridPos,
+        grid: &'a Grid<T>,
+    ) -> impl Iterator<Item = GridPos> + 'a {
+        assert_ne!(self, other, "a line needs two distinct positions");
+        let step = (other - self).reduced();
+        let start = self.ray_in(grid, step * -1).last().unwrap_or(self);
+        start.ray_in(grid, step)
+    }
+
+    /// The positions on the straight segment from this position to `end` (inclusive),
+    /// using Bresenham's line algorithm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::{pos, utils::grid::GridPos};
+    /// let segment: Vec<_> = pos!(0, 0).segment_to(pos!(4, 2)).collect();
+    /// assert_eq!(segment, [pos!(0, 0), pos!(1, 1), pos!(2, 1), pos!(3, 2), pos!(4, 2)]);
+    /// let segment: Vec<_> = pos!(1, 3).segment_to(pos!(1, 1)).collect();
+    /// assert_eq!(segment, [pos!(1, 3), pos!(1, 2), pos!(1, 1)]);
+    /// ```
+    pub fn segment_to(self, end: GridPos) -> impl Iterator<Item = GridPos> {
+        let (dx, dy) = ((end.0 - self.0).abs(), -(end.1 - self.1).abs());
+        let step = GridPos((end.0 - self.0).signum(), (end.1 - self.1).signum());
+        let mut error = dx + dy;
+        let mut next = Some(self);
+        iter::from_fn(move || {
+            let pos = next?;
+            next = (pos != end).then(|| {
+                let mut following = pos;
+                let doubled = 2 * error;
+                if doubled >= dy {
+                    error += dy;
+                    following.0 += step.0;
+                }
+                if doubled <= dx {
+                    error += dx;
+                    following.1 += step.1;
+                }
+                following
+            });
+            Some(pos)
+        })
+    }
+}
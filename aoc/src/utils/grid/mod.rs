@@ -1,41 +1,116 @@
 //! Utilities for working with a two dimensional grid of values
 //!
+//! Cells are stored contiguously in row-major order, so a grid is always rectangular.
+//!
 //! # Examples
 //! ```
-//! use aoc::grid::Grid;
+//! use aoc::{pos, utils::grid::{Grid, GridPos}};
 //!
-//! let mut grid = Grid::from_fn(10, 10, |_| 0);
-//! grid.set((1, 1), 3);
-//! assert_eq!(grid.get((0, 0)), Some(0));
-//! assert_eq!(grid.get((1, 1)), Some(3));
+//! let mut grid = Grid::from_fn(10, 10, |_| 0).unwrap();
+//! grid.set((1, 1), 3).unwrap();
+//! assert_eq!(grid.get((0, 0)), Some(&0));
+//! assert_eq!(grid[pos!(1, 1)], 3);
+//! assert_eq!(grid.row(1)[..3], [0, 3, 0]);
 //! ```
 
 mod grid_pos;
+mod line;
+mod neighbourhood;
+mod parse;
 
 use itertools::Itertools;
-use std::fmt::Debug;
+use std::{
+    fmt::{self, Debug},
+    ops::{Index, IndexMut},
+};
 
 pub use self::grid_pos::GridPos;
+pub use self::neighbourhood::Neighbourhood;
 pub use crate::pos;
 
 /// A two dimensional grid of values
 #[derive(Clone, PartialEq, Eq)]
 pub struct Grid<T> {
-    pub grid: Vec<Vec<T>>,
-    pub width: usize,
-    pub height: usize,
+    /// Row-major, `width * height` cells long
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+/// Why a grid couldn't be created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The grid would have no rows or no columns
+    Empty,
+    /// A row had a different length to the first row
+    Ragged {
+        row: usize,
+        len: usize,
+        width: usize,
+    },
+    /// `width * height` overflows
+    TooLarge { width: usize, height: usize },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid has no cells"),
+            GridError::Ragged { row, len, width } => write!(
+                f,
+                "row {row} has {len} cells but the grid is {width} cells wide"
+            ),
+            GridError::TooLarge { width, height } => {
+                write!(f, "grid of {width}x{height} cells is too large")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// Check a grid's dimensions, returning how many cells it has
+fn cell_count(width: usize, height: usize) -> Result<usize, GridError> {
+    if width == 0 || height == 0 {
+        return Err(GridError::Empty);
+    }
+    width
+        .checked_mul(height)
+        .ok_or(GridError::TooLarge { width, height })
 }
 
 impl<T> Grid<T> {
-    /// panics if grid is empty
-    pub fn new(grid: Vec<Vec<T>>) -> Self {
-        let height = grid.len();
-        let width = grid[0].len();
-        Self {
+    /// Create a grid from its rows, which must all be the same (non-zero) length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::utils::grid::{Grid, GridError};
+    /// let grid = Grid::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(grid.row(1), [3, 4]);
+    /// assert_eq!(
+    ///     Grid::new(vec![vec![1, 2], vec![3]]),
+    ///     Err(GridError::Ragged { row: 1, len: 1, width: 2 })
+    /// );
+    /// assert_eq!(Grid::<u8>::new(vec![]), Err(GridError::Empty));
+    /// ```
+    pub fn new(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(cell_count(width, height)?);
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            if cells_in_row.len() != width {
+                let len = cells_in_row.len();
+                return Err(GridError::Ragged { row, len, width });
+            }
+            cells.extend(cells_in_row);
+        }
+
+        Ok(Self {
+            cells,
             width,
             height,
-            grid,
-        }
+        })
     }
 
     /// Create a grid of the specified size and fill it with the default cell value
@@ -43,22 +118,16 @@ impl<T> Grid<T> {
     /// # Examples
     ///
     /// ```
-    /// use aoc::grid::Grid;
-    /// let grid: Grid<usize> = Grid::from_default(10, 10);
-    /// assert_eq!(grid.get((0, 0)), Some(0));
+    /// use aoc::utils::grid::Grid;
+    /// let grid: Grid<usize> = Grid::from_default(10, 5).unwrap();
+    /// assert_eq!(grid.get((9, 4)), Some(&0));
+    /// assert_eq!(grid.get((4, 9)), None);
     /// ```
-    pub fn from_default(width: usize, height: usize) -> Self
+    pub fn from_default(width: usize, height: usize) -> Result<Self, GridError>
     where
         T: Default,
     {
-        let grid = (0..height)
-            .map(|_| (0..height).map(|_| Default::default()).collect())
-            .collect();
-        Self {
-            width,
-            height,
-            grid,
-        }
+        Self::from_fn(width, height, |_| T::default())
     }
 
     /// Create a grid of the specified size and fill it by repeatingly calling the
@@ -67,70 +136,84 @@ impl<T> Grid<T> {
     /// # Examples
     ///
     /// ```
-    /// use aoc::grid::{Grid, GridPos};
-    /// let grid = Grid::from_fn(10, 10, |GridPos(x, y)| x + y);
-    /// assert_eq!(grid.get((3, 2)), Some(5));
+    /// use aoc::utils::grid::{Grid, GridPos};
+    /// let grid = Grid::from_fn(10, 5, |GridPos(x, y)| x + y).unwrap();
+    /// assert_eq!(grid.get((3, 2)), Some(&5));
+    /// assert_eq!(grid.get((9, 4)), Some(&13));
     /// ```
-    pub fn from_fn<F>(width: usize, height: usize, cell_fn: F) -> Self
+    pub fn from_fn<F>(width: usize, height: usize, cell_fn: F) -> Result<Self, GridError>
     where
         F: Fn(GridPos) -> T,
     {
-        let grid = (0..height)
-            .map(|y| (0..height).map(|x| cell_fn(pos!(x, y))).collect())
-            .collect();
-        Self {
+        let mut cells = Vec::with_capacity(cell_count(width, height)?);
+        for y in 0..height {
+            cells.extend((0..width).map(|x| cell_fn(pos!(x, y))));
+        }
+
+        Ok(Self {
+            cells,
             width,
             height,
-            grid,
-        }
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The index into `cells` of a position, if it's in bounds
+    fn index_of<C: Into<(isize, isize)>>(&self, coords: C) -> Option<usize> {
+        let (x, y) = coords.into();
+        let x = usize::try_from(x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
     }
 
-    /// Get a grid value at a given position
+    /// Get a reference to the grid value at a given position
     /// will panic if the position is out of bounds
-    pub fn get_unchecked<C: Into<(isize, isize)>>(&self, coords: C) -> T
-    where
-        T: Copy,
-    {
+    pub fn get_unchecked<C: Into<(isize, isize)>>(&self, coords: C) -> &T {
         let (x, y) = coords.into();
-        let x: usize = x.try_into().unwrap();
-        let y: usize = y.try_into().unwrap();
-        self.grid[y][x]
+        let index = self
+            .index_of((x, y))
+            .unwrap_or_else(|| panic!("Grid coords ({x}, {y}) is out of bounds."));
+        &self.cells[index]
     }
 
     /// Get a mutable reference to the grid value at a given position
     /// will panic if the position is out of bounds
     pub fn get_unchecked_mut<C: Into<(isize, isize)>>(&mut self, coords: C) -> &mut T {
         let (x, y) = coords.into();
-        let x: usize = x.try_into().unwrap();
-        let y: usize = y.try_into().unwrap();
-        &mut self.grid[y][x]
+        let index = self
+            .index_of((x, y))
+            .unwrap_or_else(|| panic!("Grid coords ({x}, {y}) is out of bounds."));
+        &mut self.cells[index]
     }
 
-    /// Get the value at a given position
+    /// Get a reference to the value at a given position
     /// Returns None when the provided position is out of bounds for the grid
     ///
     /// # Examples
     ///
     /// ```
     /// // Get a grid value using an x-y tuple
-    /// use aoc::grid::Grid;
-    /// let grid = Grid::from_default(10, 10);
-    /// assert_eq!(grid.get((0, 0)), Some(0));
+    /// use aoc::utils::grid::Grid;
+    /// let grid = Grid::from_default(10, 10).unwrap();
+    /// assert_eq!(grid.get((0, 0)), Some(&0));
     /// ```
     ///
     /// ```
-    /// // Get a grid value using a `GridPos`
-    /// use aoc::{pos, grid::{Grid, GridPos}};
-    /// let grid = Grid::from_default(10, 10);
-    /// assert_eq!(grid.get(pos![0, 0]), Some(0));
+    /// // Get a grid value using a `GridPos`, cells don't need to be `Copy`
+    /// use aoc::{pos, utils::grid::{Grid, GridPos}};
+    /// let grid = Grid::from_fn(3, 3, |GridPos(x, y)| format!("{x},{y}")).unwrap();
+    /// assert_eq!(grid.get(pos![2, 1]).map(String::as_str), Some("2,1"));
+    /// assert_eq!(grid.get(pos![3, 1]), None);
     /// ```
-    pub fn get<C: Into<(isize, isize)>>(&self, coords: C) -> Option<T>
-    where
-        T: Copy,
-    {
-        let (x, y) = coords.into();
-        ((0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y))
-            .then(|| self.get_unchecked((x, y)))
+    pub fn get<C: Into<(isize, isize)>>(&self, coords: C) -> Option<&T> {
+        self.index_of(coords).map(|index| &self.cells[index])
     }
 
     /// Set the value at a given position
@@ -139,10 +222,10 @@ impl<T> Grid<T> {
     /// # Examples
     ///
     /// ```
-    /// use aoc::grid::Grid;
-    /// let mut grid = Grid::from_default(10, 10);
-    /// grid.set((0, 0), 1);
-    /// assert_eq!(grid.get((0, 0)), Some(1));
+    /// use aoc::utils::grid::Grid;
+    /// let mut grid = Grid::from_default(10, 10).unwrap();
+    /// grid.set((0, 0), 1).unwrap();
+    /// assert_eq!(grid.get((0, 0)), Some(&1));
     /// ```
     pub fn set<C: Into<(isize, isize)> + Debug + Clone>(
         &mut self,
@@ -160,19 +243,89 @@ impl<T> Grid<T> {
     /// Get a mutable reference to the value at a given grid position
     /// Returns None when the provided position is out of bounds for the grid
     pub fn get_mut<C: Into<(isize, isize)>>(&mut self, coords: C) -> Option<&mut T> {
-        let (x, y) = coords.into();
-        ((0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y))
-            .then(|| self.get_unchecked_mut((x, y)))
+        self.index_of(coords).map(|index| &mut self.cells[index])
+    }
+
+    /// The cells of row `y`, panics if it's out of bounds
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Grid row {y} is out of bounds.");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Get an iterator over each row's cells, top to bottom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::utils::grid::Grid;
+    /// let grid: Grid<char> = "ab\ncd\n".parse().unwrap();
+    /// let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    /// assert_eq!(rows, ["ab", "cd"]);
+    /// let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    /// assert_eq!(columns, ["ac", "bd"]);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    /// Get an iterator over each column's cells (top to bottom), left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells[x..].iter().step_by(self.width))
+    }
+
+    /// Get an iterator over every position and cell in row-col order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::{pos, utils::grid::{Grid, GridPos}};
+    /// let mut grid: Grid<char> = "a.\n.b\n".parse().unwrap();
+    /// for (_, cell) in grid.iter_mut() {
+    ///     cell.make_ascii_uppercase();
+    /// }
+    /// let letters: Vec<_> = grid.iter().filter(|(_, cell)| **cell != '.').collect();
+    /// assert_eq!(letters, [(pos!(0, 0), &'A'), (pos!(1, 1), &'B')]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (GridPos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Get an iterator over every position and mutable cell in row-col order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (GridPos, &mut T)> {
+        let width = self.width;
+        let positions = (0..self.height).flat_map(move |y| (0..width).map(move |x| pos!(x, y)));
+        positions.zip(&mut self.cells)
+    }
+
+    /// Create a new grid of the same size by mapping every cell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::utils::grid::Grid;
+    /// let grid = Grid::parse_digits("12\n34\n").unwrap();
+    /// let doubled = grid.map(|&d| u32::from(d) * 2);
+    /// assert_eq!(doubled.row(1), [6, 8]);
+    /// ```
+    pub fn map<U, F>(&self, cell_fn: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(cell_fn).collect(),
+            width: self.width,
+            height: self.height,
+        }
     }
 
     /// Get an iterator over every cell value in row-col order
     pub fn cells(self) -> impl Iterator<Item = T> {
-        self.grid.into_iter().flat_map(|row| row.into_iter())
+        self.cells.into_iter()
     }
 
     /// Get a referencing iterator over every cell value in row-col order
     pub fn cells_iter(&self) -> impl Iterator<Item = &T> {
-        self.grid.iter().flat_map(|row| row.iter())
+        self.cells.iter()
     }
 
     /// Get an iterator to every grid position in row-col order
@@ -182,20 +335,51 @@ impl<T> Grid<T> {
             .map(|(y, x)| pos!(x, y))
     }
 
-    /// Print the grid using the provided formatting method
-    pub fn print_cells<F, O>(&self, fmt_fn: F)
+    /// Display the grid using the provided formatting method for each cell,
+    /// e.g. to log it with `debug!("{}", grid.display_cells(...))`
+    pub fn display_cells<F, O>(&self, fmt_fn: F) -> DisplayCells<'_, T, F>
     where
         O: std::fmt::Display,
         F: Fn(GridPos, &T) -> O,
     {
-        println!("Grid(");
-        for y in 0..self.height {
-            for x in 0..self.width {
-                print!("{}", fmt_fn(pos!(x, y), &self.grid[y][x]));
+        DisplayCells { grid: self, fmt_fn }
+    }
+}
+
+/// Index a grid by position, panics if it's out of bounds
+impl<T> Index<GridPos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: GridPos) -> &T {
+        self.get_unchecked(pos)
+    }
+}
+
+impl<T> IndexMut<GridPos> for Grid<T> {
+    fn index_mut(&mut self, pos: GridPos) -> &mut T {
+        self.get_unchecked_mut(pos)
+    }
+}
+
+/// A grid displayed with a custom format for each cell, see [`Grid::display_cells`]
+pub struct DisplayCells<'a, T, F> {
+    grid: &'a Grid<T>,
+    fmt_fn: F,
+}
+
+impl<T, F, O> std::fmt::Display for DisplayCells<'_, T, F>
+where
+    O: std::fmt::Display,
+    F: Fn(GridPos, &T) -> O,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.grid.height {
+            for (x, cell) in self.grid.row(y).iter().enumerate() {
+                write!(f, "{}", (self.fmt_fn)(pos!(x, y), cell))?;
             }
-            println!();
+            writeln!(f)?;
         }
-        println!(")")
+        Ok(())
     }
 }
 
@@ -206,8 +390,8 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Grid(")?;
         for y in 0..self.height {
-            for x in 0..self.width {
-                write!(f, "{:?} ", self.grid[y][x])?;
+            for cell in self.row(y) {
+                write!(f, "{:?} ", cell)?;
             }
             writeln!(f)?;
         }
@@ -221,8 +405,8 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for y in 0..self.height {
-            for x in 0..self.width {
-                write!(f, "{}", self.grid[y][x])?;
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
             }
             writeln!(f)?;
         }
@@ -0,0 +1,74 @@
+use super::{Grid, GridPos};
+use crate::pos;
+
+/// The offsets from a position at which its neighbours are found
+///
+/// # Examples
+///
+/// ```
+/// use aoc::{pos, utils::grid::{Grid, GridPos, Neighbourhood}};
+/// let grid = Grid::parse_digits("123\n456\n789\n").unwrap();
+///
+/// // Only in bounds neighbours are given
+/// let corner: Vec<_> = grid.neighbours8(pos!(0, 0)).map(|(_, &d)| d).collect();
+/// assert_eq!(corner, [2, 4, 5]);
+///
+/// // A knight's moves
+/// let knight = Neighbourhood(&[
+///     pos!(1, -2), pos!(2, -1), pos!(2, 1), pos!(1, 2),
+///     pos!(-1, 2), pos!(-2, 1), pos!(-2, -1), pos!(-1, -2),
+/// ]);
+/// let moves: Vec<_> = grid.neighbours_in(pos!(0, 0), knight).collect();
+/// assert_eq!(moves, [(pos!(2, 1), &6), (pos!(1, 2), &8)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Neighbourhood<'a>(pub &'a [GridPos]);
+
+impl Neighbourhood<'static> {
+    /// The four orthogonally adjacent positions
+    pub const VON_NEUMANN: Self = Self(&[pos!(-1, 0), pos!(1, 0), pos!(0, -1), pos!(0, 1)]);
+
+    /// The four diagonally adjacent positions
+    pub const DIAGONAL: Self = Self(&[pos!(-1, -1), pos!(1, -1), pos!(-1, 1), pos!(1, 1)]);
+
+    /// All eight surrounding positions, in row-col order
+    pub const MOORE: Self = Self(&[
+        pos!(-1, -1),
+        pos!(0, -1),
+        pos!(1, -1),
+        pos!(-1, 0),
+        pos!(1, 0),
+        pos!(-1, 1),
+        pos!(0, 1),
+        pos!(1, 1),
+    ]);
+}
+
+impl<T> Grid<T> {
+    /// Get the in bounds neighbours of a position, and their cells, for a given neighbourhood
+    pub fn neighbours_in<'a>(
+        &'a self,
+        pos: GridPos,
+        neighbourhood: Neighbourhood<'a>,
+    ) -> impl Iterator<Item = (GridPos, &'a T)> + 'a {
+        neighbourhood.0.iter().filter_map(move |&offset| {
+            let neighbour = pos + offset;
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    /// Get the in bounds orthogonal neighbours of a position, and their cells
+    pub fn neighbours(&self, pos: GridPos) -> impl Iterator<Item = (GridPos, &T)> {
+        self.neighbours_in(pos, Neighbourhood::VON_NEUMANN)
+    }
+
+    /// Get the in bounds diagonal neighbours of a position, and their cells
+    pub fn neighbours_diag(&self, pos: GridPos) -> impl Iterator<Item = (GridPos, &T)> {
+        self.neighbours_in(pos, Neighbourhood::DIAGONAL)
+    }
+
+    /// Get all in bounds surrounding positions, and their cells
+    pub fn neighbours8(&self, pos: GridPos) -> impl Iterator<Item = (GridPos, &T)> {
+        self.neighbours_in(pos, Neighbourhood::MOORE)
+    }
+}
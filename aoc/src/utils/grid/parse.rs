@@ -0,0 +1,202 @@
+//! Parsing grids from puzzle input, one row per line
+
+use std::{collections::HashMap, str::FromStr};
+
+use super::{cell_count, Grid, GridError, GridPos};
+use crate::{pos, ParseError, ParseResult};
+
+/// The slice of the input holding the char at a position
+fn char_at(input: &str, GridPos(x, y): GridPos) -> &str {
+    let line = input.lines().nth(y as usize).unwrap_or(input);
+    line.char_indices()
+        .nth(x as usize)
+        .map_or(line, |(i, c)| &line[i..i + c.len_utf8()])
+}
+
+/// An empty slice just after the last row of the input, for errors about what's missing
+fn end_of(input: &str) -> &str {
+    let end = input.trim_end().len();
+    &input[end..end]
+}
+
+impl GridError {
+    /// Turn this into a [`ParseError`] spanning where it happened in the grid's input,
+    /// the offending row if there is one or otherwise the end of the input.
+    /// Resolve it to a line and column with [`ParseError::locate`] as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::utils::grid::GridError;
+    /// let input = "ab\nc\n";
+    /// let err = GridError::Ragged { row: 1, len: 1, width: 2 }.at(input).locate(input);
+    /// assert_eq!(err.to_string(), "2:1: row 1 has 1 cells but the grid is 2 cells wide");
+    /// let err = GridError::Empty.at("\n").locate("\n");
+    /// assert_eq!(err.to_string(), "1:1: grid has no cells");
+    /// ```
+    pub fn at(&self, input: &str) -> ParseError {
+        let span = match *self {
+            GridError::Ragged { row, .. } => {
+                input.lines().nth(row).unwrap_or_else(|| end_of(input))
+            }
+            GridError::Empty | GridError::TooLarge { .. } => end_of(input),
+        };
+        ParseError::new(span, self.to_string())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse a grid with one row per line, mapping each char to a cell.
+    /// Chars that `cell_fn` returns `None` for are reported as unexpected,
+    /// as are rows of a different length to the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::utils::grid::Grid;
+    /// let grid = Grid::parse("#.\n.#\n", |_, c| match c {
+    ///     '#' => Some(true),
+    ///     '.' => Some(false),
+    ///     _ => None,
+    /// }).unwrap();
+    /// assert_eq!(grid.row(1), [false, true]);
+    ///
+    /// let err = Grid::parse("#.\n.x\n", |_, c| (c != 'x').then_some(c)).unwrap_err();
+    /// assert_eq!(err.message(), "unexpected char 'x'");
+    /// let input = "#.\n.\n";
+    /// let err = Grid::parse(input, |_, c| Some(c)).unwrap_err().locate(input);
+    /// assert_eq!(err.to_string(), "2:1: row 1 has 1 cells but the grid is 2 cells wide");
+    /// ```
+    pub fn parse<F>(input: &str, mut cell_fn: F) -> ParseResult<Self>
+    where
+        F: FnMut(GridPos, char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let row_start = cells.len();
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let cell = cell_fn(pos!(x, y), c).ok_or_else(|| {
+                    ParseError::new(&line[i..i + c.len_utf8()], format!("unexpected char {c:?}"))
+                })?;
+                cells.push(cell);
+            }
+
+            let len = cells.len() - row_start;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(GridError::Ragged { row: y, len, width }.at(input));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let width = width.unwrap_or(0);
+        cell_count(width, height).map_err(|err| err.at(input))?;
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parse a grid like [`Grid::parse`], but pull the position of each named marker
+    /// char out into a map, leaving `fill` in its place. Each marker must appear exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::{pos, utils::grid::{Grid, GridPos}};
+    /// let (grid, markers) = Grid::parse_with_markers(
+    ///     "S.#\n..E\n",
+    ///     &[('S', "start"), ('E', "end")],
+    ///     '.',
+    ///     |_, c| Some(c),
+    /// ).unwrap();
+    /// assert_eq!(grid.row(0), ['.', '.', '#']);
+    /// assert_eq!(markers["start"], pos!(0, 0));
+    /// assert_eq!(markers["end"], pos!(2, 1));
+    ///
+    /// // Missing markers are reported at the end of the input
+    /// let input = "S.\n..\n";
+    /// let err = Grid::parse_with_markers(input, &[('S', "start"), ('E', "end")], '.', |_, c| Some(c))
+    ///     .unwrap_err()
+    ///     .locate(input);
+    /// assert_eq!(err.to_string(), "2:3: missing end marker 'E'");
+    /// ```
+    pub fn parse_with_markers<'a, F>(
+        input: &str,
+        markers: &[(char, &'a str)],
+        fill: T,
+        mut cell_fn: F,
+    ) -> ParseResult<(Self, HashMap<&'a str, GridPos>)>
+    where
+        T: Clone,
+        F: FnMut(GridPos, char) -> Option<T>,
+    {
+        let mut found: HashMap<&str, GridPos> = HashMap::new();
+        let mut duplicate = None;
+        let grid = Self::parse(input, |pos, c| {
+            match markers.iter().find(|(marker, _)| *marker == c) {
+                Some(&(_, name)) => {
+                    if found.insert(name, pos).is_some() {
+                        duplicate.get_or_insert((name, c, pos));
+                    }
+                    Some(fill.clone())
+                }
+                None => cell_fn(pos, c),
+            }
+        })?;
+
+        if let Some((name, c, pos)) = duplicate {
+            return Err(ParseError::new(
+                char_at(input, pos),
+                format!("{name} marker {c:?} appears more than once"),
+            ));
+        }
+        if let Some((c, name)) = markers.iter().find(|(_, name)| !found.contains_key(name)) {
+            return Err(ParseError::new(
+                end_of(input),
+                format!("missing {name} marker {c:?}"),
+            ));
+        }
+
+        Ok((grid, found))
+    }
+}
+
+impl Grid<u8> {
+    /// Parse a grid of base 10 digits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc::utils::grid::Grid;
+    /// let grid = Grid::parse_digits("012\n345\n").unwrap();
+    /// assert_eq!(grid.row(1), [3, 4, 5]);
+    /// assert!(Grid::parse_digits("01\n2.\n").is_err());
+    /// ```
+    pub fn parse_digits(input: &str) -> ParseResult<Self> {
+        Self::parse(input, |_, c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+/// Parse a grid of chars as is
+///
+/// # Examples
+///
+/// ```
+/// use aoc::utils::grid::Grid;
+/// let grid: Grid<char> = "ab\ncd\n".parse().unwrap();
+/// assert_eq!(grid.row(1), ['c', 'd']);
+/// ```
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        Self::parse(s, |_, c| Some(c))
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use aoc::{parse, Answer, ParseError, ParseResult};
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl aoc::Puzzle for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = (Vec<usize>, Vec<usize>);
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        let mut lists: Self::Parsed = Default::default();
+
+        for line in input.lines() {
+            let (left, right) = line
+                .split_whitespace()
+                .collect_tuple()
+                .ok_or_else(|| ParseError::new(line, "expected two numbers"))?;
+            lists.0.push(parse::number(left)?);
+            lists.1.push(parse::number(right)?);
+        }
+
+        Ok(lists)
+    }
+
+    fn solve_part1(input: &Self::Parsed, params: &Self::Params) -> impl Into<Answer> {
+        Self::solve_part1_owned(input.clone(), params)
+    }
+
+    fn clone_parsed(input: &Self::Parsed) -> Option<Self::Parsed> {
+        Some(input.clone())
+    }
+
+    fn solve_part1_owned(
+        (mut left, mut right): Self::Parsed,
+        _params: &Self::Params,
+    ) -> impl Into<Answer> {
+        // Sort lists
+        left.sort();
+        right.sort();
+
+        // Compare matching
+        let diff_sum: usize = std::iter::zip(left.iter(), right.iter())
+            .map(|(&x, &y)| usize::abs_diff(x, y))
+            .sum();
+
+        diff_sum
+    }
+
+    fn solve_part2(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        // Count occs in right list
+        let mut right_counts: HashMap<usize, usize> = Default::default();
+        for &i in input.1.iter() {
+            right_counts.entry(i).and_modify(|x| *x += 1).or_insert(1);
+        }
+
+        input
+            .0
+            .iter()
+            .map(|x| x * right_counts.get(x).unwrap_or(&0))
+            .sum::<usize>()
+    }
+}
+
+aoc::examples!(Day01,
+    inline "
+        3   4
+        4   3
+        2   5
+        1   3
+        3   9
+        3   3
+    " => (11, 31),
+);
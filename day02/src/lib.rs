@@ -0,0 +1,87 @@
+use aoc::{parse, Answer, ParseResult};
+use itertools::Itertools;
+
+pub struct Day02;
+
+fn is_report_safe(report: &[isize]) -> bool {
+    let mut diffs = report.iter().tuple_windows::<(_, _)>().map(|(x, y)| y - x);
+    let signs_equal = diffs.clone().map(|d| d.cmp(&0)).all_equal();
+    let safe_diffs = diffs.all(|d| (1..=3).contains(&d.abs()));
+    signs_equal && safe_diffs
+}
+
+/// just brute force it, trying without every combination of up to `tolerance` levels
+/// feels like there would be a cool backtracking approach to this tho hmmm
+fn is_report_safe_with_dampening(report: &[isize], tolerance: usize) -> bool {
+    (0..=tolerance.min(report.len())).any(|removed| {
+        (0..report.len()).combinations(removed).any(|skip| {
+            let report_without_skipped = report
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !skip.contains(i))
+                .map(|(_, &level)| level)
+                .collect_vec();
+            is_report_safe(&report_without_skipped)
+        })
+    })
+}
+
+aoc::params! {
+    pub struct Params {
+        /// How many bad levels the problem dampener can remove from a report
+        pub tolerance: usize = 1,
+    }
+}
+
+impl aoc::Puzzle for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Vec<isize>>;
+    type Carry = ();
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        input
+            .lines()
+            .map(|line| line.split_whitespace().map(parse::number).collect())
+            .collect()
+    }
+
+    fn solve_part1(reports: &Self::Parsed, _params: &Params) -> impl Into<Answer> {
+        reports.iter().filter(|r| is_report_safe(r)).count()
+    }
+
+    fn solve_part2(reports: &Self::Parsed, params: &Params) -> impl Into<Answer> {
+        reports
+            .iter()
+            .filter(|r| is_report_safe_with_dampening(r, params.tolerance))
+            .count()
+    }
+}
+
+aoc::examples!(Day02,
+    inline "
+        7 6 4 2 1
+        1 2 7 8 9
+        9 7 6 2 1
+        1 3 2 4 5
+        8 6 4 4 1
+        1 3 6 7 9
+    " => (2, 4),
+    inline "
+        7 6 4 2 1
+        1 2 7 8 9
+        9 7 6 2 1
+        1 3 2 4 5
+        8 6 4 4 1
+        1 3 6 7 9
+    " with { tolerance: 0 } => (2, 2),
+);
+
+#[test]
+fn dampening_removes_first_and_last_levels() {
+    assert!(!is_report_safe(&[9, 1, 2, 3, 4]));
+    assert!(is_report_safe_with_dampening(&[9, 1, 2, 3, 4], 1));
+    assert!(is_report_safe_with_dampening(&[1, 2, 3, 4, 9], 1));
+    assert!(!is_report_safe_with_dampening(&[9, 1, 2, 3, 4], 0));
+}
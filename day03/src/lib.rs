@@ -0,0 +1,111 @@
+use aoc::{Answer, ParseResult};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    combinator::{self, value},
+    IResult,
+};
+
+pub struct Day03;
+
+#[derive(Debug, Clone)]
+pub struct Mul(u32, u32);
+
+#[derive(Debug, Clone)]
+pub enum Command {
+    Mul(Mul),
+    Do,
+    Dont,
+}
+
+impl Mul {
+    pub fn result(&self) -> u32 {
+        self.0 * self.1
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, _) = tag("mul(")(input)?;
+        let (input, x) = nom::character::complete::u32(input)?;
+        let (input, _) = tag(",")(input)?;
+        let (input, y) = nom::character::complete::u32(input)?;
+        let (input, _) = tag(")")(input)?;
+
+        Ok((input, Mul(x, y)))
+    }
+}
+
+impl Command {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        alt((
+            combinator::map(Mul::parse, Self::Mul),
+            value(Self::Do, tag("do()")),
+            value(Self::Dont, tag("don't()")),
+        ))(input)
+    }
+}
+
+impl aoc::Puzzle for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Command>;
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        let mut input = input.to_string();
+        let mut commands = Vec::new();
+        while !input.is_empty() {
+            // match a mul
+            match Command::parse(&input) {
+                Ok((rem, command)) => {
+                    input = rem.to_string();
+                    commands.push(command)
+                }
+                Err(_) => {
+                    // slow smh
+                    input.remove(0);
+                }
+            }
+        }
+
+        Ok(commands)
+    }
+
+    fn solve_part1(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        input
+            .iter()
+            .filter_map(|r| match r {
+                Command::Mul(mul) => Some(mul.result()),
+                Command::Do => None,
+                Command::Dont => None,
+            })
+            .sum::<u32>()
+    }
+
+    fn solve_part2(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        let mut enabled = true;
+        let mut sum = 0;
+        for command in input.iter() {
+            match command {
+                Command::Mul(mul) => {
+                    if enabled {
+                        sum += mul.result();
+                    }
+                }
+                Command::Do => {
+                    enabled = true;
+                }
+                Command::Dont => {
+                    enabled = false;
+                }
+            }
+        }
+
+        sum
+    }
+}
+
+aoc::examples!(Day03,
+    inline "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))" => (161, _),
+    inline "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))" => (161, 48),
+);
@@ -0,0 +1,122 @@
+use aoc::{
+    pos,
+    utils::grid::{Grid, GridPos, Neighbourhood},
+    Answer, ParseResult,
+};
+use itertools::Itertools;
+
+pub struct Day04;
+
+#[derive(Clone, Debug)]
+pub struct WordSearch(Grid<char>);
+
+pub type Kernel = &'static [&'static [char]];
+
+#[rustfmt::skip]
+static XMAS_CROSS_KERNELS: &[Kernel] = &[
+    &[
+        &['M', '*', 'M'],
+        &['*', 'A', '*'],
+        &['S', '*', 'S'],
+    ],
+
+    &[
+        &['S', '*', 'M'],
+        &['*', 'A', '*'],
+        &['S', '*', 'M'],
+    ],
+
+    &[
+        &['M', '*', 'S'],
+        &['*', 'A', '*'],
+        &['M', '*', 'S'],
+    ],
+
+    &[
+        &['S', '*', 'S'],
+        &['*', 'A', '*'],
+        &['M', '*', 'M'],
+    ],
+];
+
+impl WordSearch {
+    /// Count the times a word appears in a straight line, in any of the eight directions
+    pub fn count_words(&self, word: &str) -> usize {
+        let length = word.chars().count();
+        self.0
+            .positions()
+            .cartesian_product(Neighbourhood::MOORE.0)
+            .filter(|(pos, &step)| {
+                pos.ray_in(&self.0, step)
+                    .take(length)
+                    .map(|pos| self.0[pos])
+                    .eq(word.chars())
+            })
+            .count()
+    }
+
+    pub fn check_kernel_at(&self, kernel: Kernel, check_i: usize, check_j: usize) -> bool {
+        for (kernel_i, row) in kernel.iter().enumerate() {
+            for (kernel_j, mask) in row.iter().enumerate() {
+                // Ignore wildcard
+                if *mask == '*' {
+                    continue;
+                }
+
+                // Must be in bounds and match
+                let sample = self.0.get(pos!(check_j + kernel_j, check_i + kernel_i));
+                if sample != Some(mask) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn count_kernels(&self, kernels: &[Kernel]) -> usize {
+        kernels
+            .iter()
+            .map(|kernel| {
+                Itertools::cartesian_product(0..self.0.height(), 0..self.0.width())
+                    .filter(|(i, j)| self.check_kernel_at(kernel, *i, *j))
+                    .count()
+            })
+            .sum::<usize>()
+    }
+}
+
+impl aoc::Puzzle for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = WordSearch;
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        Ok(WordSearch(input.parse()?))
+    }
+
+    fn solve_part1(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        input.count_words("XMAS")
+    }
+
+    fn solve_part2(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        input.count_kernels(XMAS_CROSS_KERNELS)
+    }
+}
+
+aoc::examples!(Day04,
+    inline "
+        MMMSXXMASM
+        MSAMXMSMSA
+        AMXSXMAAMM
+        MSAMASMSMX
+        XMASAMXAMM
+        XXAMMXXAMA
+        SMSMSASXSS
+        SAXAMASAAA
+        MAMMMXMMMM
+        MXMXAXMASX
+    " => (18, 9),
+);
@@ -0,0 +1,129 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
+};
+
+use aoc::{parse, Answer, ParseError, ParseResult};
+use itertools::Itertools;
+
+pub struct Day05;
+
+#[derive(Debug, Clone)]
+pub struct SuccessorMap(HashMap<usize, Vec<usize>>);
+
+impl SuccessorMap {
+    pub fn update_is_valid(&self, update: &[usize]) -> bool {
+        let mut update_queue: VecDeque<_> = update.iter().collect();
+        while !update_queue.is_empty() {
+            let page = update_queue.pop_front().unwrap();
+            let successors = self.0.get(page);
+            if let Some(successors) = successors {
+                // All successors must be after this or not in the update at all
+                let right_spot = successors
+                    .iter()
+                    .all(|succ| !update.contains(succ) || update_queue.contains(&succ));
+                if !right_spot {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn fix_update(&self, update: &[usize]) -> Vec<usize> {
+        let mut update = update.iter().cloned().collect_vec();
+        update.sort_by(|a, b| {
+            let succs = self.0.get(a);
+            match succs {
+                Some(succs) => {
+                    if succs.contains(b) {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                }
+                None => Ordering::Equal,
+            }
+        });
+        update
+    }
+}
+
+pub type SafetyManual = (SuccessorMap, Vec<Vec<usize>>);
+
+impl aoc::Puzzle for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = SafetyManual;
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        // Lets start by splitting it in two at the first blank line
+        let lines = input.lines().collect_vec();
+        let Some(blank) = lines.iter().position(|line| line.is_empty()) else {
+            let end = input.trim_end().len();
+            return Err(ParseError::new(
+                &input[end..end],
+                "expected a blank line between the ordering rules and the updates",
+            ));
+        };
+        let (ordering, updates) = (&lines[..blank], &lines[blank + 1..]);
+
+        // we put each ordering entry into a hashmap
+        // In this set, if (k,v) is present then k < v
+        let mut successor_map = HashMap::new();
+        for line in ordering {
+            let (x, y) = parse::split_once(line, "|")?;
+            let x = parse::number(x)?;
+            let y = parse::number(y)?;
+            successor_map
+                .entry(x)
+                .and_modify(|succs: &mut Vec<usize>| succs.push(y))
+                .or_insert_with(|| vec![y]);
+        }
+
+        // Parse each update
+        let updates = updates
+            .iter()
+            .map(|l| l.split(",").map(parse::number).collect())
+            .collect::<ParseResult<_>>()?;
+
+        Ok((SuccessorMap(successor_map), updates))
+    }
+
+    fn solve_part1(
+        (successor_map, updates): &Self::Parsed,
+        _params: &Self::Params,
+    ) -> impl Into<Answer> {
+        updates
+            .iter()
+            .filter(|update| successor_map.update_is_valid(update))
+            .map(|update| update[update.len() / 2])
+            .sum::<usize>()
+    }
+
+    fn solve_part2(
+        (successor_map, updates): &Self::Parsed,
+        _params: &Self::Params,
+    ) -> impl Into<Answer> {
+        updates
+            .iter()
+            .filter(|update| !successor_map.update_is_valid(update))
+            .map(|update| successor_map.fix_update(update))
+            .map(|update| update[update.len() / 2])
+            .sum::<usize>()
+    }
+}
+
+aoc::examples!(Day05, "sample.txt" => (143, 123));
+
+#[test]
+fn parses_crlf_input() {
+    use aoc::Puzzle;
+
+    let input = include_str!("../sample.txt").replace('\n', "\r\n");
+    let parsed = Day05::parse(&input).unwrap();
+    assert_eq!(Day05::solve_part1(&parsed, &()).into(), Answer::from(143));
+}
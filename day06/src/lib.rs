@@ -0,0 +1,189 @@
+use std::collections::HashSet;
+
+use aoc::{
+    progress::ProgressIterator,
+    utils::{
+        direction::Dir,
+        grid::{Grid, GridPos},
+    },
+    Answer, ParseResult,
+};
+
+pub struct Day06;
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Free,
+    Obstacle,
+}
+
+#[derive(Clone, Debug)]
+pub struct Map(Grid<Cell>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Continue {
+    Continue,
+    Stop,
+}
+
+impl Map {
+    pub fn trace_from(
+        &self,
+        start_pos: GridPos,
+        start_dir: Dir,
+        mut transition_cb: impl FnMut(GridPos, Dir) -> Continue,
+    ) {
+        let mut pos = start_pos;
+        let mut dir = start_dir;
+
+        loop {
+            if transition_cb(pos, dir) == Continue::Stop {
+                break;
+            };
+
+            let check_pos = pos + dir.into();
+            match self.0.get(check_pos) {
+                None => break,
+                Some(cell) => match cell {
+                    Cell::Free => {
+                        pos = check_pos;
+                    }
+                    Cell::Obstacle => dir = dir.turn_right(),
+                },
+            }
+        }
+    }
+
+    /// Every position the guard visits before leaving the map
+    pub fn guard_path(&self, start_pos: GridPos, start_dir: Dir) -> HashSet<GridPos> {
+        let mut visited = HashSet::new();
+        self.trace_from(start_pos, start_dir, |pos, _| {
+            visited.insert(pos);
+            Continue::Continue
+        });
+        visited
+    }
+
+    /// Count the positions on the guard's path where an obstacle would cause a loop
+    pub fn count_loop_obstacles(
+        &self,
+        start_pos: GridPos,
+        start_dir: Dir,
+        visited: HashSet<GridPos>,
+    ) -> usize {
+        let mut obstacles: HashSet<GridPos> = Default::default();
+
+        // Consider inserting an obstacle at each point
+        // (into our own copy of the grid)
+        let mut grid = self.clone();
+        for pos in visited.into_iter().progress("obstacles") {
+            // Skip start pos
+            if pos == start_pos {
+                continue;
+            }
+
+            // Would inserting an obstacle here cause a loop?
+            grid.0.set(pos, Cell::Obstacle).unwrap();
+
+            let mut does_loop = false;
+            let mut past: HashSet<(GridPos, Dir)> = Default::default();
+            grid.trace_from(start_pos, start_dir, |pos, dir| {
+                if past.contains(&(pos, dir)) {
+                    does_loop = true;
+                    Continue::Stop
+                } else {
+                    past.insert((pos, dir));
+                    Continue::Continue
+                }
+            });
+
+            if does_loop {
+                obstacles.insert(pos);
+            }
+
+            grid.0.set(pos, Cell::Free).unwrap();
+        }
+
+        obstacles.len()
+    }
+}
+
+aoc::params! {
+    pub struct Params {
+        /// Which way the guard is facing at the start
+        pub start_dir: Dir = Dir::North,
+    }
+}
+
+impl aoc::Puzzle for Day06 {
+    const DAY: u8 = 6;
+
+    /// The map, and the guard's starting position
+    type Parsed = (Map, GridPos);
+    /// Positions visited by the guard
+    type Carry = HashSet<GridPos>;
+    type Params = Params;
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        let (grid, markers) =
+            Grid::parse_with_markers(input, &[('^', "guard")], Cell::Free, |_, c| match c {
+                '#' => Some(Cell::Obstacle),
+                '.' => Some(Cell::Free),
+                _ => None,
+            })?;
+
+        Ok((Map(grid), markers["guard"]))
+    }
+
+    fn solve_part1((grid, start_pos): &Self::Parsed, params: &Params) -> impl Into<Answer> {
+        grid.guard_path(*start_pos, params.start_dir).len()
+    }
+
+    fn solve_part2((grid, start_pos): &Self::Parsed, params: &Params) -> impl Into<Answer> {
+        let visited = grid.guard_path(*start_pos, params.start_dir);
+        grid.count_loop_obstacles(*start_pos, params.start_dir, visited)
+    }
+
+    fn solve_part1_carry(
+        (grid, start_pos): &Self::Parsed,
+        params: &Params,
+    ) -> (impl Into<Answer>, Self::Carry) {
+        let visited = grid.guard_path(*start_pos, params.start_dir);
+        (visited.len(), visited)
+    }
+
+    fn solve_part2_carry(
+        (grid, start_pos): &Self::Parsed,
+        params: &Params,
+        visited: Self::Carry,
+    ) -> impl Into<Answer> {
+        grid.count_loop_obstacles(*start_pos, params.start_dir, visited)
+    }
+}
+
+aoc::examples!(Day06,
+    inline "
+        ....#.....
+        .........#
+        ..........
+        ..#.......
+        .......#..
+        ..........
+        .#..^.....
+        ........#.
+        #.........
+        ......#...
+    " => (41, 6),
+    inline "
+        ....#.....
+        .........#
+        ..........
+        ..#.......
+        .......#..
+        ..........
+        .#..^.....
+        ........#.
+        #.........
+        ......#...
+    " with { start_dir: Dir::East } => (6, _),
+);
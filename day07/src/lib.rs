@@ -0,0 +1,186 @@
+use aoc::{parse, Answer, ParseError, ParseResult};
+
+pub struct Day07;
+
+#[derive(Clone, Debug)]
+pub struct EquationTest {
+    test: usize,
+    values: Vec<usize>,
+}
+
+fn concat_nums(a: usize, b: usize) -> usize {
+    (format!("{}{}", a, b)).parse().unwrap()
+}
+
+impl EquationTest {
+    pub fn solveable(&self) -> bool {
+        let (head, tail) = self.values.split_first().unwrap();
+        self._solveable(*head, tail)
+    }
+
+    pub fn _solveable(&self, current: usize, values: &[usize]) -> bool {
+        let Some((head, tail)) = values.split_first() else {
+            return current == self.test;
+        };
+
+        self._solveable(current * head, tail) || self._solveable(current + head, tail)
+    }
+
+    pub fn solveable_with_concat(&self) -> bool {
+        let (head, tail) = self.values.split_first().unwrap();
+        self._solveable_with_concat(*head, tail)
+    }
+
+    pub fn _solveable_with_concat(&self, current: usize, values: &[usize]) -> bool {
+        let Some((head, tail)) = values.split_first() else {
+            return current == self.test;
+        };
+
+        self._solveable_with_concat(current * head, tail)
+            || self._solveable_with_concat(current + head, tail)
+            || self._solveable_with_concat(concat_nums(current, *head), tail)
+    }
+
+    /// Same as [`Self::solveable`] and [`Self::solveable_with_concat`], but searching
+    /// backwards from the test value so that impossible operators are pruned early
+    pub fn solveable_backwards(&self, with_concat: bool) -> bool {
+        Self::_solveable_backwards(self.test, &self.values, with_concat)
+    }
+
+    fn _solveable_backwards(target: usize, values: &[usize], with_concat: bool) -> bool {
+        let Some((&last, rest)) = values.split_last() else {
+            return false;
+        };
+        if rest.is_empty() {
+            return target == last;
+        }
+
+        // Multiplying by zero reaches zero whatever came before
+        let multiplied = match last {
+            0 => target == 0,
+            _ => {
+                target.is_multiple_of(last)
+                    && Self::_solveable_backwards(target / last, rest, with_concat)
+            }
+        };
+        let added = target >= last && Self::_solveable_backwards(target - last, rest, with_concat);
+        let concatenated = with_concat && {
+            let shift = 10_usize.pow(last.checked_ilog10().unwrap_or(0) + 1);
+            target >= last
+                && (target - last).is_multiple_of(shift)
+                && Self::_solveable_backwards((target - last) / shift, rest, with_concat)
+        };
+        multiplied || added || concatenated
+    }
+}
+
+impl aoc::Puzzle for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<EquationTest>;
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        input
+            .lines()
+            .map(|line| {
+                let (test, values) = parse::split_once(line, ":")?;
+                let test = parse::number(test)?;
+                let values = parse::strip_prefix(values, " ")?
+                    .split_whitespace()
+                    .map(parse::number)
+                    .collect::<ParseResult<Vec<_>>>()?;
+                if values.is_empty() {
+                    return Err(ParseError::new(line, "expected at least one value"));
+                }
+
+                Ok(EquationTest { test, values })
+            })
+            .collect()
+    }
+
+    fn solve_part1(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        input
+            .iter()
+            .filter(|r| r.solveable())
+            .map(|r| r.test)
+            .sum::<usize>()
+    }
+
+    fn solve_part2(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        input
+            .iter()
+            .filter(|r| r.solveable_with_concat())
+            .map(|r| r.test)
+            .sum::<usize>()
+    }
+}
+
+aoc::examples!(Day07,
+    inline "
+        190: 10 19
+        3267: 81 40 27
+        83: 17 5
+        156: 15 6
+        7290: 6 8 6 15
+        161011: 16 10 13
+        192: 17 8 14
+        21037: 9 7 18 13
+        292: 11 6 16 20
+    " => (3749, 11387),
+);
+
+#[test]
+fn test_7290() {
+    let record = EquationTest {
+        test: 7290,
+        values: vec![6, 8, 6, 15],
+    };
+    assert!(record.solveable_with_concat());
+}
+
+#[test]
+fn test_concat() {
+    assert_eq!(concat_nums(12, 345), 12345);
+}
+
+#[test]
+fn backwards_search_agrees() {
+    use aoc::differential::Differential;
+    use itertools::Itertools;
+
+    fn sum_solveable(input: &[EquationTest], with_concat: bool) -> usize {
+        input
+            .iter()
+            .filter(|r| r.solveable_backwards(with_concat))
+            .map(|r| r.test)
+            .sum()
+    }
+
+    Differential::<Day07>::new()
+        .part1("backwards", |input, _| sum_solveable(input, false))
+        .part2("backwards", |input, _| sum_solveable(input, true))
+        .random(500, |rng| {
+            (0..rng.range(1..6))
+                .map(|_| {
+                    let values: Vec<usize> =
+                        (0..rng.range(1..5)).map(|_| rng.range(0..20)).collect();
+                    // Usually aim for a reachable test value
+                    let test = if rng.chance(0.7) {
+                        values[1..]
+                            .iter()
+                            .fold(values[0], |acc, &v| match rng.range(0..3) {
+                                0 => acc + v,
+                                1 => acc * v,
+                                _ => concat_nums(acc, v),
+                            })
+                    } else {
+                        rng.range(0..1000)
+                    };
+                    format!("{test}: {}\n", values.iter().join(" "))
+                })
+                .collect()
+        })
+        .assert_agree();
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use aoc::{
+    utils::grid::{Grid, GridPos},
+    Answer, ParseResult,
+};
+use itertools::Itertools;
+
+pub struct Day08;
+
+fn get_antennae(grid: &Grid<char>) -> Vec<(GridPos, char)> {
+    // Pull out the antanae
+    grid.iter()
+        .filter(|(_, cell)| **cell != '.')
+        .map(|(loc, cell)| (loc, *cell))
+        .collect()
+}
+
+impl aoc::Puzzle for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Grid<char>;
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        input.parse()
+    }
+
+    fn solve_part1(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        // Consider each pair of antenae
+        let antinodes: HashSet<_> = get_antennae(input)
+            .into_iter()
+            .tuple_combinations()
+            // Only keep matching antennae
+            .filter(|((_, a), (_, b))| a == b)
+            // Pull out possible antinode positions
+            .flat_map(|((pos_a, _), (pos_b, _))| {
+                let a_to_b = pos_b - pos_a;
+                let possible_1 = pos_a - a_to_b;
+                let possible_2 = pos_b + a_to_b;
+                vec![possible_1, possible_2].into_iter()
+            })
+            // Keep inbounds positions
+            .filter(|pos| pos.in_grid(input))
+            .collect();
+
+        antinodes.len()
+    }
+
+    fn solve_part2(input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        // Consider each pair of antenae
+        let antinodes: HashSet<_> = get_antennae(input)
+            .into_iter()
+            .tuple_combinations()
+            // Only keep matching antennae
+            .filter(|((_, a), (_, b))| a == b)
+            // Pull out possible antinode positions
+            // this time with harmonic resonancy :taps brain:
+            .flat_map(|((pos_a, _), (pos_b, _))| pos_a.line_through(pos_b, input))
+            .collect();
+
+        antinodes.len()
+    }
+}
+
+aoc::examples!(Day08,
+    inline "
+        ............
+        ........0...
+        .....0......
+        .......0....
+        ....0.......
+        ......A.....
+        ............
+        ............
+        ........A...
+        .........A..
+        ............
+        ............
+    " => (14, 34),
+);
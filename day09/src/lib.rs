@@ -0,0 +1,329 @@
+use std::iter;
+
+use aoc::{log::debug, parse, Answer, ParseError, ParseResult};
+use itertools::Itertools;
+
+pub struct Day09;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Run {
+    Empty { length: usize },
+    Filled { id: usize, length: usize },
+}
+
+impl Run {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Run::Empty { .. })
+    }
+
+    pub fn length(&self) -> usize {
+        match self {
+            Run::Empty { length } => *length,
+            Run::Filled { length, .. } => *length,
+        }
+    }
+}
+
+fn compute_runs_checksum(runs: &[Run]) -> usize {
+    runs.iter()
+        .flat_map(|r| match r {
+            Run::Empty { length } => iter::repeat_n(0, *length),
+            Run::Filled { id, length } => iter::repeat_n(*id, *length),
+        })
+        .enumerate()
+        .map(|(id, i)| id * i)
+        .sum::<usize>()
+}
+
+/// Part 1 by moving single blocks between two cursors, rather than splicing runs
+pub fn compact_blocks_checksum(runs: &[Run]) -> usize {
+    let mut blocks = runs
+        .iter()
+        .flat_map(|run| match *run {
+            Run::Empty { length } => iter::repeat_n(None, length),
+            Run::Filled { id, length } => iter::repeat_n(Some(id), length),
+        })
+        .collect_vec();
+
+    let (mut start, mut end) = (0, blocks.len());
+    while start < end {
+        if blocks[start].is_some() {
+            start += 1;
+        } else if blocks[end - 1].is_none() {
+            end -= 1;
+        } else {
+            blocks.swap(start, end - 1);
+        }
+    }
+
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, id)| i * id.unwrap_or(0))
+        .sum()
+}
+
+/// Part 2 by tracking where each file and gap starts, rather than splicing runs
+pub fn compact_files_checksum(runs: &[Run]) -> usize {
+    // (position, length, id) of each file, and (position, length) of each gap
+    let mut files = Vec::new();
+    let mut gaps = Vec::new();
+    let mut position = 0;
+    for run in runs {
+        match *run {
+            Run::Empty { length } => gaps.push((position, length)),
+            Run::Filled { id, length } => files.push((position, length, id)),
+        }
+        position += run.length();
+    }
+
+    for (file_position, length, _) in files.iter_mut().rev() {
+        if let Some(gap) = gaps
+            .iter_mut()
+            .take_while(|(gap_position, _)| gap_position < file_position)
+            .find(|(_, gap_length)| gap_length >= length)
+        {
+            *file_position = gap.0;
+            gap.0 += *length;
+            gap.1 -= *length;
+        }
+    }
+
+    files
+        .iter()
+        .flat_map(|&(position, length, id)| (position..position + length).map(move |i| i * id))
+        .sum()
+}
+
+impl std::fmt::Debug for Run {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Run::Empty { length } => write!(f, "{}", ".".repeat(*length)),
+            Run::Filled { id, length } => write!(f, "{}", id.to_string().repeat(*length)),
+        }
+    }
+}
+
+impl aoc::Puzzle for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Run>;
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        let runs = input
+            .lines()
+            .take(1)
+            .flat_map(parse::digits)
+            .map_ok(|d| d as usize)
+            .collect::<ParseResult<Vec<_>>>()?
+            .into_iter()
+            .enumerate()
+            .map(|(i, length)| {
+                if i % 2 == 0 {
+                    Run::Filled { id: i / 2, length }
+                } else {
+                    Run::Empty { length }
+                }
+            })
+            .filter(|run| run.length() > 0)
+            .collect_vec();
+        if runs.iter().all(Run::is_empty) {
+            return Err(ParseError::new(
+                input,
+                "expected a disk map with at least one file",
+            ));
+        }
+        Ok(runs)
+    }
+
+    fn solve_part1(runs: &Self::Parsed, params: &Self::Params) -> impl Into<Answer> {
+        Self::solve_part1_owned(runs.clone(), params)
+    }
+
+    fn solve_part2(runs: &Self::Parsed, params: &Self::Params) -> impl Into<Answer> {
+        Self::solve_part2_owned(runs.clone(), params)
+    }
+
+    fn clone_parsed(runs: &Self::Parsed) -> Option<Self::Parsed> {
+        Some(runs.clone())
+    }
+
+    fn solve_part1_owned(mut runs: Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        // We want to move runs from the end into empty runs at the start
+        // so to do that lets look for empties to fill until there aren't any
+        for index in 0..runs.len() {
+            if index >= runs.len() {
+                break;
+            }
+
+            // Skip filled runs from this end
+            let Run::Empty { length } = runs[index] else {
+                continue;
+            };
+
+            // Trim any gaps at end of runs
+            for index in (0..runs.len()).rev() {
+                if runs[index].is_empty() {
+                    runs.pop();
+                } else {
+                    break;
+                }
+            }
+
+            // Was this gap at the end? then there's nothing left to move
+            if index >= runs.len() {
+                break;
+            }
+
+            // Get next file from end of runs
+            let next_file = runs.pop().unwrap();
+            let Run::Filled {
+                id: file_id,
+                length: file_length,
+            } = next_file
+            else {
+                panic!();
+            };
+
+            // Is this run too long?
+            if file_length > length {
+                // Split it into two and
+                // leave the correct amount behind
+                let leftovers_length = file_length - length;
+                let new_run = Run::Filled {
+                    id: file_id,
+                    length: leftovers_length,
+                };
+                runs.push(new_run);
+
+                // Swap empty for filled
+                runs[index] = Run::Filled {
+                    id: file_id,
+                    length,
+                };
+            }
+
+            // Is this run too short to fill the gap?
+            if file_length < length {
+                // We need to split the empty into two
+                let remaining_length = length - file_length;
+                let new_empty = Run::Empty {
+                    length: remaining_length,
+                };
+
+                // Swap empty for filled
+                runs[index] = Run::Filled {
+                    id: file_id,
+                    length: length.min(file_length),
+                };
+
+                runs.insert(index + 1, new_empty);
+            }
+
+            // Is the run the perfect length? :relieved:
+            if file_length == length {
+                // Swap empty for filled
+                runs[index] = Run::Filled {
+                    id: file_id,
+                    length,
+                };
+            }
+        }
+
+        compute_runs_checksum(&runs)
+    }
+
+    fn solve_part2_owned(mut runs: Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        // Find max id
+        let max_id = runs
+            .iter()
+            .filter_map(|r| match r {
+                Run::Empty { .. } => None,
+                Run::Filled { id, .. } => Some(id),
+            })
+            .max()
+            .unwrap();
+
+        // Attempt to move each block once
+        // starting with maximum id
+        for current_id in (0..=*max_id).rev() {
+            // Find where this block is
+            let (index, run) = runs
+                .iter()
+                .enumerate()
+                .find(|(_, r)| match r {
+                    Run::Empty { .. } => false,
+                    Run::Filled { id, .. } => *id == current_id,
+                })
+                .unwrap();
+
+            // Its a file... I hope..
+            let Run::Filled { length, id } = *run else {
+                panic!();
+            };
+
+            // Now scan from the left and look for empty space
+            if let Some((hole_index, hole)) = (0..index)
+                .map(|i| runs[i])
+                .enumerate()
+                .find(|(_, run)| run.is_empty() && run.length() >= length)
+            {
+                // Swap the file for a hole
+                runs[index] = Run::Empty { length };
+
+                // And set the empty hole to be this
+                runs[hole_index] = Run::Filled { id, length };
+
+                // But was there extra room?
+                if hole.length() > length {
+                    runs.insert(
+                        hole_index + 1,
+                        Run::Empty {
+                            length: hole.length() - length,
+                        },
+                    );
+                }
+            }
+        }
+
+        debug!(
+            "compacted disk: {}",
+            runs.iter().map(|r| format!("{:?}", r)).join("")
+        );
+        compute_runs_checksum(&runs)
+    }
+}
+
+aoc::examples!(Day09,
+    inline "2333133121414131402" => (1928, 2858),
+    // Moving the last file into the gap before it leaves nothing after the gap
+    inline "121" => (1, 1),
+);
+
+#[test]
+fn indexed_compaction_agrees() {
+    aoc::differential::Differential::<Day09>::new()
+        .part1("blocks", |runs, _| compact_blocks_checksum(runs))
+        .part2("indexed", |runs, _| compact_files_checksum(runs))
+        .random(300, |rng| {
+            // Files are never empty, but gaps can be
+            (0..rng.range(1..30))
+                .map(|i| match i % 2 {
+                    0 => rng.range(1..10),
+                    _ => rng.range(0..10),
+                })
+                .join("")
+        })
+        .assert_agree();
+}
+
+#[test]
+fn rejects_disk_maps_without_files() {
+    use aoc::Puzzle;
+
+    for input in ["", "0", "01", "010"] {
+        assert!(Day09::parse(input).is_err(), "{input:?} should be rejected");
+    }
+}
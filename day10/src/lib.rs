@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use aoc::{
+    utils::grid::{Grid, GridPos},
+    Answer, ParseResult,
+};
+
+pub struct Day10;
+
+fn score_trailhead(grid: &Grid<u8>, pos: GridPos, distinct: bool) -> usize {
+    // Non-zeros cannot have score
+    if grid[pos] != 0 {
+        return 0;
+    }
+
+    // Determine the score
+    let mut score = 0;
+    let mut visited = HashSet::new();
+    let mut open = vec![pos];
+    while let Some(next) = open.pop() {
+        // Mark as visited
+        visited.insert(next);
+        let height = grid[next];
+
+        // Add to score
+        if height == 9 {
+            score += 1;
+        }
+
+        // Flood fill
+        open.extend(
+            grid.neighbours(next)
+                .filter(|(_, &neighbour)| neighbour == height + 1)
+                .map(|(pos, _)| pos)
+                .filter(|pos| distinct || !visited.contains(pos)),
+        );
+    }
+
+    score
+}
+
+impl aoc::Puzzle for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Grid<u8>;
+    type Carry = ();
+    type Params = ();
+
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        Grid::parse_digits(input)
+    }
+
+    fn solve_part1(grid: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        grid.positions()
+            .map(|pos| score_trailhead(grid, pos, false))
+            .sum::<usize>()
+    }
+
+    fn solve_part2(grid: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {
+        grid.positions()
+            .map(|pos| score_trailhead(grid, pos, true))
+            .sum::<usize>()
+    }
+}
+
+aoc::examples!(Day10,
+    inline "
+        89010123
+        78121874
+        87430965
+        96549874
+        45678903
+        32019012
+        01329801
+        10456732
+    " => (36, 81),
+);
@@ -0,0 +1 @@
+aoc::bench_main!(day11::Day11);
@@ -1,11 +1,9 @@
-use std::fmt::Debug;
-
+use aoc::{parse, Answer, ParseError, ParseResult};
 use hashbag::HashBag;
-use itertools::Itertools;
 
 pub struct Day11;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct Stones(HashBag<usize>);
 
 impl Stones {
@@ -22,7 +20,7 @@ impl Stones {
                 new_bag.insert_many(1, count);
             }
             x if (x.ilog10() + 1) % 2 == 0 => {
-                let n = (x.ilog10() + 1) / 2;
+                let n = x.ilog10().div_ceil(2);
                 let p = 10_usize.pow(n);
                 new_bag.insert_many(x / p, count);
                 new_bag.insert_many(x % p, count);
@@ -35,37 +33,97 @@ impl Stones {
         self.0 = new_bag;
     }
 
+    /// The stones after blinking some number of times
+    pub fn blinked(mut self, times: usize) -> Self {
+        for _ in 0..times {
+            self.blink();
+        }
+        self
+    }
+
     pub fn size(&self) -> usize {
         self.0.len()
     }
 }
 
+aoc::params! {
+    pub struct Params {
+        pub part1_blinks: usize = 25,
+        pub part2_blinks: usize = 75,
+    }
+}
+
 impl aoc::Puzzle for Day11 {
+    const DAY: u8 = 11;
+
     type Parsed = Stones;
+    /// Stones after part 1's blinks
+    type Carry = Stones;
+    type Params = Params;
 
-    fn parse(input: &str) -> Self::Parsed {
-        Stones::new(
-            input
-                .lines()
-                .next()
-                .unwrap()
-                .split_whitespace()
-                .map(|x| x.parse::<usize>().unwrap())
-                .collect_vec(),
-        )
+    fn parse(input: &str) -> ParseResult<Self::Parsed> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new(input, "expected a line of stones"))?;
+        let stones = line
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<ParseResult<Vec<usize>>>()?;
+        Ok(Stones::new(stones))
     }
 
-    fn solve_part1(mut input: Self::Parsed) -> impl Debug {
-        for _ in 0..25 {
-            input.blink();
-        }
-        input.size()
+    fn solve_part1(stones: &Self::Parsed, params: &Params) -> impl Into<Answer> {
+        Self::solve_part1_carry(stones, params).0
     }
 
-    fn solve_part2(mut input: Self::Parsed) -> impl Debug {
-        for _ in 0..75 {
-            input.blink();
+    fn solve_part2(stones: &Self::Parsed, params: &Params) -> impl Into<Answer> {
+        Self::solve_part2_owned(stones.clone(), params)
+    }
+
+    fn clone_parsed(stones: &Self::Parsed) -> Option<Self::Parsed> {
+        Some(stones.clone())
+    }
+
+    fn solve_part1_owned(stones: Self::Parsed, params: &Params) -> impl Into<Answer> {
+        stones.blinked(params.part1_blinks).size()
+    }
+
+    fn solve_part2_owned(stones: Self::Parsed, params: &Params) -> impl Into<Answer> {
+        stones.blinked(params.part2_blinks).size()
+    }
+
+    fn solve_part1_carry(
+        stones: &Self::Parsed,
+        params: &Params,
+    ) -> (impl Into<Answer>, Self::Carry) {
+        Self::solve_part1_carry_owned(stones.clone(), params)
+    }
+
+    fn solve_part1_carry_owned(
+        stones: Self::Parsed,
+        params: &Params,
+    ) -> (impl Into<Answer>, Self::Carry) {
+        let stones = stones.blinked(params.part1_blinks);
+        (stones.size(), stones)
+    }
+
+    fn solve_part2_carry(
+        input: &Self::Parsed,
+        params: &Params,
+        stones: Self::Carry,
+    ) -> impl Into<Answer> {
+        let (part1_blinks, part2_blinks) = (params.part1_blinks, params.part2_blinks);
+        // Can't carry on from part 1 if it has already blinked too many times
+        if part2_blinks < part1_blinks {
+            return Self::solve_part2(input, params).into();
         }
-        input.size()
+
+        stones.blinked(part2_blinks - part1_blinks).size().into()
     }
 }
+
+aoc::examples!(Day11,
+    inline "125 17" => (55312, 65601038650482_usize),
+    inline "125 17" with { part1_blinks: 6, part2_blinks: 25 } => (22, 55312),
+);
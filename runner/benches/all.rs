@@ -0,0 +1,13 @@
+use aoc::bench::criterion::{criterion_group, criterion_main, Criterion};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+    for entry in runner::registry().iter() {
+        if let Some(input) = aoc::bench::load_input(root, entry.day) {
+            (entry.bench)(c, &input);
+        }
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+use aoc::Registry;
+
+/// Every puzzle in the workspace
+pub fn registry() -> Registry {
+    aoc::registry![
+        day01::Day01,
+        day02::Day02,
+        day03::Day03,
+        day04::Day04,
+        day05::Day05,
+        day06::Day06,
+        day07::Day07,
+        day08::Day08,
+        day09::Day09,
+        day10::Day10,
+        day11::Day11,
+    ]
+}
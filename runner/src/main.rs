@@ -0,0 +1,123 @@
+mod scaffold;
+
+use std::process::ExitCode;
+
+use aoc::{DaySelection, OutputFormat, Registry, RunOptions};
+use clap::{Parser, Subcommand};
+use runner::registry;
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code 2024 puzzles")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or more days, e.g. `6`, `all`, `3..7`, `3..=7` or `1,4,9`
+    Run {
+        days: DaySelection,
+
+        /// Read the input from this path, or `-` for stdin, instead of searching for it.
+        /// Only allowed when a single day is selected
+        #[arg(long, value_name = "PATH")]
+        input: Option<String>,
+
+        #[command(flatten)]
+        options: RunOptions,
+    },
+    /// Create a new day crate from the templates and add it to the workspace
+    New { day: u8 },
+}
+
+/// Run every selected day, returning whether they all succeeded
+fn run_days(
+    registry: &Registry,
+    days: &DaySelection,
+    input: Option<&str>,
+    options: &RunOptions,
+) -> bool {
+    let entries: Vec<_> = registry.select(days).collect();
+    if entries.is_empty() {
+        eprintln!("error: no registered puzzles match {days:?}");
+        return false;
+    }
+    if let (Some(path), [_, _, ..]) = (input, entries.as_slice()) {
+        eprintln!(
+            "error: --input {path} can't be used for {} days, select a single day",
+            entries.len()
+        );
+        return false;
+    }
+
+    let mut ledger = match options.load_ledger() {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+
+    let mut success = true;
+    for entry in entries {
+        if options.format == OutputFormat::Text {
+            println!("[Day {:02}]", entry.day);
+        }
+        let result = options
+            .read_input(entry.day, input)
+            .and_then(|input| (entry.run)(&input, options, ledger.as_ref()));
+        match result {
+            Ok(report) => {
+                success &= !report.failed();
+                if let Some(ledger) = &mut ledger {
+                    report.record_into(ledger);
+                }
+            }
+            Err(err) => {
+                options.report_error(entry.day, &err);
+                success = false;
+            }
+        }
+    }
+
+    if let Some(ledger) = &ledger {
+        if let Err(err) = options.save_ledger(ledger) {
+            eprintln!("{err}");
+            success = false;
+        }
+    }
+
+    success
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let registry = registry();
+
+    let success = match cli.command {
+        Command::Run {
+            days,
+            input,
+            options,
+        } => {
+            options.init_output();
+            let success = run_days(&registry, &days, input.as_deref(), &options);
+            aoc::runner::exit_if_abandoned();
+            success
+        }
+        Command::New { day } => match scaffold::new_day(day) {
+            Ok(()) => true,
+            Err(err) => {
+                eprintln!("error: {err}");
+                false
+            }
+        },
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,135 @@
+//! Scaffolding for new day crates, generated from the files in `templates/`
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::{value, DocumentMut, InlineTable};
+
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../../templates/Cargo.tmpl.toml"),
+    ),
+    ("src/lib.rs", include_str!("../../templates/lib.tmpl.rs")),
+    ("src/main.rs", include_str!("../../templates/main.tmpl.rs")),
+    (
+        "benches/puzzle.rs",
+        include_str!("../../templates/bench.tmpl.rs"),
+    ),
+    ("sample.txt", ""),
+];
+
+/// Fill in the `{{day}}` (zero padded) and `{{day_number}}` placeholders of a template
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{day}}", &format!("{day:02}"))
+        .replace("{{day_number}}", &day.to_string())
+}
+
+/// Find the workspace root by searching upwards from the current directory
+fn workspace_root() -> Result<PathBuf, String> {
+    let cwd = env::current_dir().map_err(|e| format!("Can't get current directory: {e}"))?;
+    cwd.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| format!("No workspace Cargo.toml found above {}", cwd.display()))
+}
+
+fn read_manifest(path: &Path) -> Result<DocumentMut, String> {
+    fs::read_to_string(path)
+        .map_err(|e| format!("Can't read {}: {e}", path.display()))?
+        .parse()
+        .map_err(|e| format!("Can't parse {}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("Can't write {}: {e}", path.display()))
+}
+
+/// Add the crate to the workspace `members` list
+fn add_workspace_member(root: &Path, name: &str) -> Result<(), String> {
+    let path = root.join("Cargo.toml");
+    let mut manifest = read_manifest(&path)?;
+    let members = manifest["workspace"]["members"]
+        .as_array_mut()
+        .ok_or("Workspace has no members list")?;
+    if !members.iter().any(|member| member.as_str() == Some(name)) {
+        // Keep the runner last, after all the days
+        let index = members
+            .iter()
+            .position(|member| {
+                member
+                    .as_str()
+                    .is_some_and(|m| !m.starts_with("day") && m > name)
+            })
+            .unwrap_or(members.len());
+        members.insert(index, name);
+    }
+    write(&path, manifest.to_string())
+}
+
+/// Add the crate as a dependency of the runner and to its registry
+fn add_to_runner(root: &Path, name: &str, puzzle: &str) -> Result<(), String> {
+    let path = root.join("runner/Cargo.toml");
+    let mut manifest = read_manifest(&path)?;
+    let mut dependency = InlineTable::new();
+    dependency.insert("version", "0.1.0".into());
+    dependency.insert("path", format!("../{name}").into());
+    manifest["dependencies"][name] = value(dependency);
+    if let Some(dependencies) = manifest["dependencies"].as_table_like_mut() {
+        dependencies.sort_values();
+    }
+    write(&path, manifest.to_string())?;
+
+    let path = root.join("runner/src/lib.rs");
+    let source =
+        fs::read_to_string(&path).map_err(|e| format!("Can't read {}: {e}", path.display()))?;
+    let entry = format!("{name}::{puzzle},");
+    if source.contains(&entry) {
+        return Ok(());
+    }
+    let start = source
+        .find("aoc::registry![")
+        .ok_or("Runner has no registry")?;
+    let end = start
+        + source[start..]
+            .find("\n    ]")
+            .ok_or("Runner registry is unterminated")?;
+    let source = format!("{}\n        {entry}{}", &source[..end], &source[end..]);
+    write(&path, source)
+}
+
+/// Create a new `dayNN` crate and add it to the workspace and runner
+pub fn new_day(day: u8) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {day} is not in the advent calendar"));
+    }
+
+    let root = workspace_root()?;
+    let name = format!("day{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Can't create {}: {e}", parent.display()))?;
+        }
+        write(&path, render(template, day))?;
+    }
+    println!("[Created {}]", dir.display());
+
+    add_workspace_member(&root, &name)?;
+    add_to_runner(&root, &name, &format!("Day{day:02}"))?;
+    println!("[Added {name} to the workspace and runner]");
+
+    Ok(())
+}
@@ -0,0 +1 @@
+aoc::bench_main!(day{{day}}::Day{{day}});
@@ -0,0 +1,21 @@
+use aoc::{Answer, ParseResult};
+
+pub struct Day{{day}};
+
+impl aoc::Puzzle for Day{{day}} {
+    const DAY: u8 = {{day_number}};
+
+    type Parsed = ();
+    type Carry = ();
+    type Params = ();
+
+    fn parse(_input: &str) -> ParseResult<Self::Parsed> {
+        Ok(())
+    }
+
+    fn solve_part1(_input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {}
+
+    fn solve_part2(_input: &Self::Parsed, _params: &Self::Params) -> impl Into<Answer> {}
+}
+
+aoc::examples!(Day{{day}}, "sample.txt" => (_, _));
@@ -0,0 +1,5 @@
+use day{{day}}::Day{{day}};
+
+fn main() {
+    aoc::run_puzzle::<Day{{day}}>()
+}